@@ -0,0 +1,99 @@
+// api-server/src/routes.rs
+use actix_web::{web, HttpResponse, Scope};
+use blockchain_core::models::Transaction;
+use common::types::{Address, Hash};
+use tracing::{error, info, instrument};
+
+use crate::handlers::AppState;
+
+/// Configure the API routes
+pub fn configure_routes() -> Scope {
+    web::scope("/api")
+        // Health check endpoint
+        .route("/health", web::get().to(health_check))
+        // Transaction analysis endpoints
+        .route("/transactions/{hash}", web::get().to(get_transaction_analysis))
+        // Smart contract analysis endpoints
+        .route("/contracts/{address}", web::get().to(get_contract_analysis))
+}
+
+/// Health check endpoint handler
+async fn health_check() -> HttpResponse {
+    info!("Health check requested");
+    HttpResponse::Ok().json(serde_json::json!({
+        "status": "ok",
+        "version": env!("CARGO_PKG_VERSION"),
+    }))
+}
+
+/// Handler for transaction analysis requests
+///
+/// Runs the transaction through every registered `TransactionAnalyzer` and
+/// returns the aggregated `AnalysisReport` as JSON, with a top-level `risk`
+/// (the maximum severity across findings) and findings sorted most-severe
+/// first.
+#[instrument(skip(state), fields(hash = %hash))]
+async fn get_transaction_analysis(
+    hash: web::Path<String>,
+    state: web::Data<AppState>,
+) -> HttpResponse {
+    info!("Transaction analysis requested for hash: {}", hash);
+
+    // Placeholder transaction construction until a blockchain data provider
+    // is wired into AppState to fetch the real transaction for this hash.
+    let tx = Transaction::new(
+        Hash(hash.to_string()),
+        Address("0x0000000000000000000000000000000000000000".to_string()),
+        None,
+        0,
+        0,
+        0,
+        0,
+        vec![],
+    );
+
+    match state.transaction_processor.analyze(&tx).await {
+        Ok(mut report) => {
+            report
+                .findings
+                .sort_by(|a, b| b.risk.cmp(&a.risk).then_with(|| a.id.cmp(&b.id)));
+            HttpResponse::Ok().json(report)
+        }
+        Err(e) => {
+            error!("Transaction analysis failed for {}: {}", hash, e);
+            HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": format!("Transaction analysis failed: {}", e)
+            }))
+        }
+    }
+}
+
+/// Handler for smart contract analysis requests
+///
+/// Runs the contract through `AppState::security_analyzer` and returns its
+/// `SecurityAnalysis` as JSON, with a top-level `risk` and findings sorted
+/// alphabetically, mirroring `get_transaction_analysis`'s `{ risk, findings }`
+/// shape.
+#[instrument(skip(state), fields(address = %address))]
+async fn get_contract_analysis(address: web::Path<String>, state: web::Data<AppState>) -> HttpResponse {
+    info!("Contract analysis requested for address: {}", address);
+
+    let target = Address(address.to_string());
+
+    match state.security_analyzer.analyze_contract(&target).await {
+        Ok(mut analysis) => {
+            analysis.findings.sort();
+            HttpResponse::Ok().json(serde_json::json!({
+                "address": address.to_string(),
+                "risk": analysis.risk_level.to_string(),
+                "findings": analysis.findings,
+            }))
+        }
+        Err(e) => {
+            error!("Contract analysis failed for {}: {}", address, e);
+            HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": format!("Contract analysis failed: {}", e)
+            }))
+        }
+    }
+}
@@ -54,6 +54,14 @@ pub trait BlockchainDataProvider: Send + Sync {
     /// Performs a security analysis on a smart contract
     async fn analyze_contract(&self, address: &Address) -> Result<SecurityAnalysis>;
 
+    /// Retrieves the deployed runtime bytecode at an address, or an empty
+    /// vector for an externally-owned account
+    async fn get_code(&self, address: &Address) -> Result<Vec<u8>>;
+
+    /// Retrieves the raw 32-byte value stored at `slot` in a contract's
+    /// storage
+    async fn get_storage_at(&self, address: &Address, slot: &[u8; 32]) -> Result<[u8; 32]>;
+
     /// Checks if an address is a contract
     async fn is_contract(&self, address: &Address) -> Result<bool> {
         debug!("Checking if address {} is a contract", address);
@@ -150,10 +158,23 @@ mod tests {
         async fn analyze_contract(&self, _address: &Address) -> Result<SecurityAnalysis> {
             Ok(SecurityAnalysis {
                 risk_level: RiskLevel::Low,
+                risk_score: 0,
                 findings: vec![],
                 metadata: HashMap::new(),
             })
         }
+
+        async fn get_code(&self, address: &Address) -> Result<Vec<u8>> {
+            if address.0 == "0xcontract" {
+                Ok(vec![0, 1, 2])
+            } else {
+                Ok(vec![])
+            }
+        }
+
+        async fn get_storage_at(&self, _address: &Address, _slot: &[u8; 32]) -> Result<[u8; 32]> {
+            Ok([0u8; 32])
+        }
     }
 
     #[tokio::test]
@@ -167,5 +188,11 @@ mod tests {
         // Test is_contract
         assert!(provider.is_contract(&Address("0xcontract".to_string())).await.unwrap());
         assert!(!provider.is_contract(&Address("0xnotcontract".to_string())).await.unwrap());
+
+        // Test get_code
+        let code = provider.get_code(&Address("0xcontract".to_string())).await.unwrap();
+        assert_eq!(code, vec![0, 1, 2]);
+        let no_code = provider.get_code(&Address("0xnotcontract".to_string())).await.unwrap();
+        assert!(no_code.is_empty());
     }
 }
\ No newline at end of file
@@ -9,10 +9,20 @@ use tracing::{debug, info};
 
 pub mod models;
 pub mod blockchain;
+pub mod middleware;
+pub mod multi_node;
+pub mod quorum;
+pub mod rpc;
+pub mod traced;
 
 // Re-export main types and traits
 pub use blockchain::{BlockchainDataProvider, BlockchainError};
-pub use models::{Transaction, SmartContract, SecurityAnalysis};
+pub use middleware::{CachingLayer, CallMetrics, MetricsLayer, ProviderMiddleware, RetryLayer};
+pub use models::{bloom_from_transactions, SecurityAnalysis, SignatureComponents, SmartContract, Transaction};
+pub use multi_node::{MultiNodeProvider, NodeClient};
+pub use quorum::{QuorumPolicy, QuorumProvider};
+pub use rpc::{HttpJsonRpcProvider, IpcJsonRpcProvider, JsonRpcProvider, JsonRpcTransport, NodeClientProbe};
+pub use traced::TracedProvider;
 
 /// Initialize logging for the blockchain-core crate
 pub async fn init() {
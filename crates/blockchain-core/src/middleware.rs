@@ -0,0 +1,602 @@
+// blockchain-core/src/middleware.rs
+//! Composable layers over a `BlockchainDataProvider`
+//!
+//! `ProviderMiddleware` mirrors `BlockchainDataProvider`'s method set with
+//! default implementations that forward to an inner provider, so a layer
+//! only needs to override the handful of methods it actually changes. Each
+//! `ProviderMiddleware` implementor then becomes a `BlockchainDataProvider`
+//! itself via [`impl_provider_via_middleware`], so layers stack:
+//! `RetryLayer::new(CachingLayer::new(rpc_provider))`.
+//!
+//! There's no blanket `impl<M: ProviderMiddleware> BlockchainDataProvider for M`
+//! here: that would conflict (E0119) with every concrete `BlockchainDataProvider`
+//! impl elsewhere in this crate, since the compiler can't prove a given type
+//! won't also implement `ProviderMiddleware`. The macro generates the same
+//! forwarding impl per layer type instead, without the coherence conflict.
+
+use crate::blockchain::{BlockchainDataProvider, BlockchainError};
+use crate::models::{SecurityAnalysis, SmartContract, Transaction};
+use async_trait::async_trait;
+use common::{
+    async_utils::{retry_with_policy, RetryPolicy},
+    error::{Error, Result},
+    types::{Address, Hash, TimeRange},
+};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+/// A composable wrapper around an inner `BlockchainDataProvider`
+///
+/// Implementations override only the methods whose behavior they change;
+/// every other method falls back to its default, which simply forwards to
+/// `self.inner()`. The `BlockchainDataProvider` impl that
+/// `impl_provider_via_middleware!` generates for each layer routes every
+/// provider call through these (possibly overridden) methods rather than
+/// straight to `Inner`, so a stack of layers each get a chance to
+/// intercept.
+///
+/// # Examples
+///
+/// ```
+/// use blockchain_core::middleware::ProviderMiddleware;
+/// use blockchain_core::blockchain::BlockchainDataProvider;
+/// use common::error::Result;
+/// use common::types::Address;
+/// use async_trait::async_trait;
+///
+/// struct PassThrough<P>(P);
+///
+/// #[async_trait]
+/// impl<P: BlockchainDataProvider> ProviderMiddleware for PassThrough<P> {
+///     type Inner = P;
+///     fn inner(&self) -> &P { &self.0 }
+///     // No methods overridden: every call falls through to `inner()` unchanged.
+/// }
+/// ```
+#[async_trait]
+pub trait ProviderMiddleware: Send + Sync {
+    /// The provider (or next layer) this middleware wraps
+    type Inner: BlockchainDataProvider;
+
+    /// Returns a reference to the wrapped provider
+    fn inner(&self) -> &Self::Inner;
+
+    async fn get_transaction(&self, hash: &Hash) -> Result<Transaction> {
+        self.inner().get_transaction(hash).await
+    }
+
+    async fn get_contract(&self, address: &Address) -> Result<SmartContract> {
+        self.inner().get_contract(address).await
+    }
+
+    async fn get_transactions_in_range(&self, range: TimeRange) -> Result<Vec<Transaction>> {
+        self.inner().get_transactions_in_range(range).await
+    }
+
+    async fn get_address_transactions(&self, address: &Address) -> Result<Vec<Transaction>> {
+        self.inner().get_address_transactions(address).await
+    }
+
+    async fn get_balance(&self, address: &Address) -> Result<u64> {
+        self.inner().get_balance(address).await
+    }
+
+    async fn get_nonce(&self, address: &Address) -> Result<u64> {
+        self.inner().get_nonce(address).await
+    }
+
+    async fn analyze_contract(&self, address: &Address) -> Result<SecurityAnalysis> {
+        self.inner().analyze_contract(address).await
+    }
+
+    async fn get_code(&self, address: &Address) -> Result<Vec<u8>> {
+        self.inner().get_code(address).await
+    }
+
+    async fn get_storage_at(&self, address: &Address, slot: &[u8; 32]) -> Result<[u8; 32]> {
+        self.inner().get_storage_at(address, slot).await
+    }
+}
+
+/// Implements `BlockchainDataProvider` for a single-type-parameter
+/// `ProviderMiddleware` type (`$ty<P>`) by forwarding every method to its
+/// `ProviderMiddleware` implementation.
+///
+/// See the module-level note on why this is a macro and not a blanket impl.
+macro_rules! impl_provider_via_middleware {
+    ($ty:ident) => {
+        #[async_trait]
+        impl<P: BlockchainDataProvider> BlockchainDataProvider for $ty<P> {
+            async fn get_transaction(&self, hash: &Hash) -> Result<Transaction> {
+                ProviderMiddleware::get_transaction(self, hash).await
+            }
+
+            async fn get_contract(&self, address: &Address) -> Result<SmartContract> {
+                ProviderMiddleware::get_contract(self, address).await
+            }
+
+            async fn get_transactions_in_range(&self, range: TimeRange) -> Result<Vec<Transaction>> {
+                ProviderMiddleware::get_transactions_in_range(self, range).await
+            }
+
+            async fn get_address_transactions(&self, address: &Address) -> Result<Vec<Transaction>> {
+                ProviderMiddleware::get_address_transactions(self, address).await
+            }
+
+            async fn get_balance(&self, address: &Address) -> Result<u64> {
+                ProviderMiddleware::get_balance(self, address).await
+            }
+
+            async fn get_nonce(&self, address: &Address) -> Result<u64> {
+                ProviderMiddleware::get_nonce(self, address).await
+            }
+
+            async fn analyze_contract(&self, address: &Address) -> Result<SecurityAnalysis> {
+                ProviderMiddleware::analyze_contract(self, address).await
+            }
+
+            async fn get_code(&self, address: &Address) -> Result<Vec<u8>> {
+                ProviderMiddleware::get_code(self, address).await
+            }
+
+            async fn get_storage_at(&self, address: &Address, slot: &[u8; 32]) -> Result<[u8; 32]> {
+                ProviderMiddleware::get_storage_at(self, address, slot).await
+            }
+        }
+    };
+}
+pub(crate) use impl_provider_via_middleware;
+
+const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Memoizes `get_contract`/`get_balance` by address, each entry expiring
+/// after a TTL (default 60 seconds)
+///
+/// Only these two methods are cached: contract bytecode is effectively
+/// immutable once deployed and balances are cheap to serve slightly stale,
+/// whereas transactions, nonces, and security analyses are not safe to
+/// memoize the same way.
+pub struct CachingLayer<P> {
+    inner: P,
+    ttl: Duration,
+    contracts: Mutex<HashMap<Address, (SmartContract, Instant)>>,
+    balances: Mutex<HashMap<Address, (u64, Instant)>>,
+}
+
+impl<P: BlockchainDataProvider> CachingLayer<P> {
+    /// Wraps `inner` with the default 60-second cache TTL
+    pub fn new(inner: P) -> Self {
+        Self::with_ttl(inner, DEFAULT_CACHE_TTL)
+    }
+
+    /// Wraps `inner` with a custom cache TTL
+    pub fn with_ttl(inner: P, ttl: Duration) -> Self {
+        Self {
+            inner,
+            ttl,
+            contracts: Mutex::new(HashMap::new()),
+            balances: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn cached<T: Clone>(cache: &Mutex<HashMap<Address, (T, Instant)>>, address: &Address, ttl: Duration) -> Option<T> {
+        let cache = cache.lock().unwrap();
+        let (value, cached_at) = cache.get(address)?;
+        if cached_at.elapsed() < ttl {
+            Some(value.clone())
+        } else {
+            None
+        }
+    }
+}
+
+#[async_trait]
+impl<P: BlockchainDataProvider> ProviderMiddleware for CachingLayer<P> {
+    type Inner = P;
+
+    fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    async fn get_contract(&self, address: &Address) -> Result<SmartContract> {
+        if let Some(contract) = Self::cached(&self.contracts, address, self.ttl) {
+            debug!("Cache hit for contract {}", address);
+            return Ok(contract);
+        }
+
+        let contract = self.inner.get_contract(address).await?;
+        self.contracts
+            .lock()
+            .unwrap()
+            .insert(address.clone(), (contract.clone(), Instant::now()));
+        Ok(contract)
+    }
+
+    async fn get_balance(&self, address: &Address) -> Result<u64> {
+        if let Some(balance) = Self::cached(&self.balances, address, self.ttl) {
+            debug!("Cache hit for balance of {}", address);
+            return Ok(balance);
+        }
+
+        let balance = self.inner.get_balance(address).await?;
+        self.balances
+            .lock()
+            .unwrap()
+            .insert(address.clone(), (balance, Instant::now()));
+        Ok(balance)
+    }
+}
+impl_provider_via_middleware!(CachingLayer);
+
+/// Classifies which blockchain errors are worth retrying: transient
+/// connection and RPC failures, not application-level errors like a
+/// missing contract or an invalid hash
+fn is_retryable_blockchain_error(error: &Error) -> bool {
+    if matches!(error, Error::Network(_)) {
+        return true;
+    }
+    matches!(
+        error.downcast_ref::<BlockchainError>(),
+        Some(BlockchainError::RPCError(_)) | Some(BlockchainError::ConnectionError(_))
+    )
+}
+
+/// Default retry policy: 3 retries, 100ms initial backoff, 5s cap
+fn default_retry_policy() -> RetryPolicy {
+    RetryPolicy::new(3, Duration::from_millis(100), Duration::from_secs(5))
+        .with_retryable(is_retryable_blockchain_error)
+}
+
+/// Retries every provider call on transient connection/RPC errors, with
+/// full-jitter exponential backoff
+pub struct RetryLayer<P> {
+    inner: P,
+    policy: RetryPolicy,
+}
+
+impl<P: BlockchainDataProvider> RetryLayer<P> {
+    /// Wraps `inner` with the default retry policy (3 retries, 100ms initial
+    /// backoff, 5s cap), retrying `BlockchainError::RPCError`/`ConnectionError`
+    pub fn new(inner: P) -> Self {
+        Self::with_policy(inner, default_retry_policy())
+    }
+
+    /// Wraps `inner` with a custom retry policy
+    pub fn with_policy(inner: P, policy: RetryPolicy) -> Self {
+        Self { inner, policy }
+    }
+}
+
+#[async_trait]
+impl<P: BlockchainDataProvider> ProviderMiddleware for RetryLayer<P> {
+    type Inner = P;
+
+    fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    async fn get_transaction(&self, hash: &Hash) -> Result<Transaction> {
+        retry_with_policy(|| self.inner.get_transaction(hash), self.policy.clone()).await
+    }
+
+    async fn get_contract(&self, address: &Address) -> Result<SmartContract> {
+        retry_with_policy(|| self.inner.get_contract(address), self.policy.clone()).await
+    }
+
+    async fn get_transactions_in_range(&self, range: TimeRange) -> Result<Vec<Transaction>> {
+        retry_with_policy(|| self.inner.get_transactions_in_range(range.clone()), self.policy.clone()).await
+    }
+
+    async fn get_address_transactions(&self, address: &Address) -> Result<Vec<Transaction>> {
+        retry_with_policy(|| self.inner.get_address_transactions(address), self.policy.clone()).await
+    }
+
+    async fn get_balance(&self, address: &Address) -> Result<u64> {
+        retry_with_policy(|| self.inner.get_balance(address), self.policy.clone()).await
+    }
+
+    async fn get_nonce(&self, address: &Address) -> Result<u64> {
+        retry_with_policy(|| self.inner.get_nonce(address), self.policy.clone()).await
+    }
+
+    async fn analyze_contract(&self, address: &Address) -> Result<SecurityAnalysis> {
+        retry_with_policy(|| self.inner.analyze_contract(address), self.policy.clone()).await
+    }
+
+    async fn get_code(&self, address: &Address) -> Result<Vec<u8>> {
+        retry_with_policy(|| self.inner.get_code(address), self.policy.clone()).await
+    }
+
+    async fn get_storage_at(&self, address: &Address, slot: &[u8; 32]) -> Result<[u8; 32]> {
+        retry_with_policy(|| self.inner.get_storage_at(address, slot), self.policy.clone()).await
+    }
+}
+impl_provider_via_middleware!(RetryLayer);
+
+/// Call count, error count, and total latency recorded for a single
+/// provider method by `MetricsLayer`
+#[derive(Debug, Clone, Default)]
+pub struct CallMetrics {
+    pub calls: u64,
+    pub errors: u64,
+    pub total_duration: Duration,
+}
+
+/// Records per-method call counts, error counts, and latency for every
+/// provider call, without changing any call's behavior or result
+pub struct MetricsLayer<P> {
+    inner: P,
+    metrics: Mutex<HashMap<&'static str, CallMetrics>>,
+}
+
+impl<P: BlockchainDataProvider> MetricsLayer<P> {
+    /// Wraps `inner`, starting with empty metrics
+    pub fn new(inner: P) -> Self {
+        Self {
+            inner,
+            metrics: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Snapshots the metrics recorded so far, keyed by method name
+    pub fn metrics(&self) -> HashMap<String, CallMetrics> {
+        self.metrics
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(name, metrics)| (name.to_string(), metrics.clone()))
+            .collect()
+    }
+
+    async fn record<T, F>(&self, method: &'static str, future: F) -> Result<T>
+    where
+        F: Future<Output = Result<T>>,
+    {
+        let start = Instant::now();
+        let result = future.await;
+        let elapsed = start.elapsed();
+
+        let mut metrics = self.metrics.lock().unwrap();
+        let entry = metrics.entry(method).or_default();
+        entry.calls += 1;
+        entry.total_duration += elapsed;
+        if result.is_err() {
+            entry.errors += 1;
+        }
+
+        result
+    }
+}
+
+#[async_trait]
+impl<P: BlockchainDataProvider> ProviderMiddleware for MetricsLayer<P> {
+    type Inner = P;
+
+    fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    async fn get_transaction(&self, hash: &Hash) -> Result<Transaction> {
+        self.record("get_transaction", self.inner.get_transaction(hash)).await
+    }
+
+    async fn get_contract(&self, address: &Address) -> Result<SmartContract> {
+        self.record("get_contract", self.inner.get_contract(address)).await
+    }
+
+    async fn get_transactions_in_range(&self, range: TimeRange) -> Result<Vec<Transaction>> {
+        self.record("get_transactions_in_range", self.inner.get_transactions_in_range(range)).await
+    }
+
+    async fn get_address_transactions(&self, address: &Address) -> Result<Vec<Transaction>> {
+        self.record("get_address_transactions", self.inner.get_address_transactions(address)).await
+    }
+
+    async fn get_balance(&self, address: &Address) -> Result<u64> {
+        self.record("get_balance", self.inner.get_balance(address)).await
+    }
+
+    async fn get_nonce(&self, address: &Address) -> Result<u64> {
+        self.record("get_nonce", self.inner.get_nonce(address)).await
+    }
+
+    async fn analyze_contract(&self, address: &Address) -> Result<SecurityAnalysis> {
+        self.record("analyze_contract", self.inner.analyze_contract(address)).await
+    }
+
+    async fn get_code(&self, address: &Address) -> Result<Vec<u8>> {
+        self.record("get_code", self.inner.get_code(address)).await
+    }
+
+    async fn get_storage_at(&self, address: &Address, slot: &[u8; 32]) -> Result<[u8; 32]> {
+        self.record("get_storage_at", self.inner.get_storage_at(address, slot)).await
+    }
+}
+impl_provider_via_middleware!(MetricsLayer);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::types::RiskLevel;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+
+    /// Shares its call counters with the test through the `Arc`s that
+    /// `new`/`failing` return, since wrapping the provider in a layer
+    /// consumes it by value.
+    struct CountingProvider {
+        contract_calls: Arc<AtomicU32>,
+        balance_calls: Arc<AtomicU32>,
+        fail_n_times: Arc<AtomicU32>,
+    }
+
+    impl CountingProvider {
+        fn new() -> (Self, Arc<AtomicU32>, Arc<AtomicU32>) {
+            Self::failing(0)
+        }
+
+        fn failing(n: u32) -> (Self, Arc<AtomicU32>, Arc<AtomicU32>) {
+            let contract_calls = Arc::new(AtomicU32::new(0));
+            let balance_calls = Arc::new(AtomicU32::new(0));
+            let provider = Self {
+                contract_calls: Arc::clone(&contract_calls),
+                balance_calls: Arc::clone(&balance_calls),
+                fail_n_times: Arc::new(AtomicU32::new(n)),
+            };
+            (provider, contract_calls, balance_calls)
+        }
+    }
+
+    #[async_trait]
+    impl BlockchainDataProvider for CountingProvider {
+        async fn get_transaction(&self, hash: &Hash) -> Result<Transaction> {
+            Ok(Transaction::new(
+                hash.clone(),
+                Address("0xabc".to_string()),
+                None,
+                0,
+                0,
+                0,
+                0,
+                vec![],
+            ))
+        }
+
+        async fn get_contract(&self, address: &Address) -> Result<SmartContract> {
+            self.contract_calls.fetch_add(1, Ordering::SeqCst);
+            Ok(SmartContract {
+                address: address.clone(),
+                bytecode: vec![1, 2, 3],
+                creator: Address("0xabc".to_string()),
+                creation_tx: "0x1".to_string(),
+                storage: HashMap::new(),
+                timestamp: 0,
+            })
+        }
+
+        async fn get_transactions_in_range(&self, _range: TimeRange) -> Result<Vec<Transaction>> {
+            Ok(Vec::new())
+        }
+
+        async fn get_address_transactions(&self, _address: &Address) -> Result<Vec<Transaction>> {
+            Ok(Vec::new())
+        }
+
+        async fn get_balance(&self, address: &Address) -> Result<u64> {
+            let remaining = self.fail_n_times.load(Ordering::SeqCst);
+            if remaining > 0 {
+                self.fail_n_times.fetch_sub(1, Ordering::SeqCst);
+                return Err(Error::Other(Box::new(BlockchainError::RPCError(
+                    "temporarily unavailable".to_string(),
+                ))));
+            }
+            self.balance_calls.fetch_add(1, Ordering::SeqCst);
+            let _ = address;
+            Ok(42)
+        }
+
+        async fn get_nonce(&self, _address: &Address) -> Result<u64> {
+            Ok(0)
+        }
+
+        async fn analyze_contract(&self, _address: &Address) -> Result<SecurityAnalysis> {
+            Ok(SecurityAnalysis {
+                risk_level: RiskLevel::None,
+                risk_score: 0,
+                findings: Vec::new(),
+                metadata: HashMap::new(),
+            })
+        }
+
+        async fn get_code(&self, _address: &Address) -> Result<Vec<u8>> {
+            Ok(Vec::new())
+        }
+
+        async fn get_storage_at(&self, _address: &Address, _slot: &[u8; 32]) -> Result<[u8; 32]> {
+            Ok([0u8; 32])
+        }
+    }
+
+    #[tokio::test]
+    async fn test_caching_layer_memoizes_contract_lookups() {
+        let (provider, contract_calls, _) = CountingProvider::new();
+        let layer = CachingLayer::with_ttl(provider, Duration::from_secs(60));
+        let address = Address("0xabc".to_string());
+
+        layer.get_contract(&address).await.unwrap();
+        layer.get_contract(&address).await.unwrap();
+
+        assert_eq!(contract_calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_caching_layer_refetches_after_ttl_expires() {
+        let (provider, _, balance_calls) = CountingProvider::new();
+        let layer = CachingLayer::with_ttl(provider, Duration::from_millis(1));
+        let address = Address("0xabc".to_string());
+
+        layer.get_balance(&address).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        layer.get_balance(&address).await.unwrap();
+
+        assert_eq!(balance_calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_retry_layer_retries_rpc_errors_and_eventually_succeeds() {
+        let (provider, _, _) = CountingProvider::failing(2);
+        let layer = RetryLayer::with_policy(
+            provider,
+            RetryPolicy::new(3, Duration::from_millis(1), Duration::from_millis(10))
+                .with_retryable(is_retryable_blockchain_error),
+        );
+
+        let balance = layer.get_balance(&Address("0xabc".to_string())).await.unwrap();
+        assert_eq!(balance, 42);
+    }
+
+    #[tokio::test]
+    async fn test_retry_layer_gives_up_after_exhausting_retries() {
+        let (provider, _, _) = CountingProvider::failing(10);
+        let layer = RetryLayer::with_policy(
+            provider,
+            RetryPolicy::new(2, Duration::from_millis(1), Duration::from_millis(5))
+                .with_retryable(is_retryable_blockchain_error),
+        );
+
+        let result = layer.get_balance(&Address("0xabc".to_string())).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_metrics_layer_records_calls_and_errors() {
+        let (provider, _, _) = CountingProvider::failing(1);
+        let layer = MetricsLayer::new(provider);
+        let address = Address("0xabc".to_string());
+
+        assert!(layer.get_balance(&address).await.is_err());
+        assert!(layer.get_balance(&address).await.is_ok());
+
+        let metrics = layer.metrics();
+        let balance_metrics = metrics.get("get_balance").unwrap();
+        assert_eq!(balance_metrics.calls, 2);
+        assert_eq!(balance_metrics.errors, 1);
+    }
+
+    #[tokio::test]
+    async fn test_layers_compose() {
+        let (provider, _, balance_calls) = CountingProvider::failing(1);
+        let stacked = MetricsLayer::new(RetryLayer::new(CachingLayer::new(provider)));
+
+        let address = Address("0xabc".to_string());
+        let balance = stacked.get_balance(&address).await.unwrap();
+        assert_eq!(balance, 42);
+
+        // Cached on the second call, so the inner provider's counters don't move.
+        stacked.get_balance(&address).await.unwrap();
+        assert_eq!(balance_calls.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,577 @@
+// blockchain-core/src/models.rs
+use common::bloom::Bloom;
+use common::types::{Address, Hash};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+use std::collections::HashMap;
+
+/// Represents a blockchain transaction with its metadata
+///
+/// # Examples
+///
+/// ```
+/// use blockchain_core::models::Transaction;
+/// use common::types::{Address, Hash};
+///
+/// let tx = Transaction {
+///     hash: Hash("0x123".to_string()),
+///     from: Address("0xabc".to_string()),
+///     to: Some(Address("0xdef".to_string())),
+///     value: 1000,
+///     gas_price: 50,
+///     gas_limit: 21000,
+///     nonce: 5,
+///     data: vec![1, 2, 3],
+///     timestamp: 1645484400,
+///     signature: None,
+/// };
+///
+/// assert_eq!(tx.gas_price, 50);
+/// assert!(tx.to.is_some());
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Transaction {
+    /// Transaction hash
+    pub hash: Hash,
+    /// Sender address
+    pub from: Address,
+    /// Recipient address (None for contract creation)
+    pub to: Option<Address>,
+    /// Transaction value in wei
+    pub value: u64,
+    /// Gas price in wei
+    pub gas_price: u64,
+    /// Gas limit
+    pub gas_limit: u64,
+    /// Transaction nonce
+    pub nonce: u64,
+    /// Transaction data
+    pub data: Vec<u8>,
+    /// Transaction timestamp
+    pub timestamp: u64,
+    /// ECDSA `(r, s, v)` signature over [`Transaction::signing_hash`], when
+    /// the transaction has been signed
+    pub signature: Option<SignatureComponents>,
+}
+
+/// The `(r, s, v)` components of an ECDSA signature over a transaction's
+/// [`Transaction::signing_hash`]
+///
+/// # Examples
+///
+/// ```
+/// use blockchain_core::models::SignatureComponents;
+///
+/// let sig = SignatureComponents {
+///     r: [1u8; 32],
+///     s: [2u8; 32],
+///     v: 0,
+/// };
+/// assert_eq!(sig.v, 0);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignatureComponents {
+    /// `r` component of the signature
+    pub r: [u8; 32],
+    /// `s` component of the signature
+    pub s: [u8; 32],
+    /// Recovery id (0 or 1 for pre-EIP-155 transactions)
+    pub v: u8,
+}
+
+impl Transaction {
+    /// Calculates the total transaction cost (value + gas cost)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blockchain_core::models::Transaction;
+    /// use common::types::{Address, Hash};
+    ///
+    /// let tx = Transaction::new(
+    ///     Hash("0x123".to_string()),
+    ///     Address("0xabc".to_string()),
+    ///     Some(Address("0xdef".to_string())),
+    ///     1000,  // value
+    ///     50,    // gas price
+    ///     21000, // gas limit
+    ///     5,
+    ///     vec![],
+    /// );
+    ///
+    /// // Total cost = value + (gas_price * gas_limit)
+    /// assert_eq!(tx.total_cost(), 1000 + (50 * 21000));
+    /// ```
+    pub fn total_cost(&self) -> u64 {
+        self.value + (self.gas_price * self.gas_limit)
+    }
+
+    /// Checks if the transaction is a contract creation
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blockchain_core::models::Transaction;
+    /// use common::types::{Address, Hash};
+    ///
+    /// // Contract creation transaction (no 'to' address)
+    /// let contract_tx = Transaction::new(
+    ///     Hash("0x123".to_string()),
+    ///     Address("0xabc".to_string()),
+    ///     None,
+    ///     0,
+    ///     50,
+    ///     21000,
+    ///     5,
+    ///     vec![1, 2, 3], // Contract bytecode
+    /// );
+    ///
+    /// assert!(contract_tx.is_contract_creation());
+    /// ```
+    pub fn is_contract_creation(&self) -> bool {
+        self.to.is_none() && !self.data.is_empty()
+    }
+
+    /// Returns the age of the transaction in seconds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blockchain_core::models::Transaction;
+    /// use common::types::{Address, Hash};
+    /// use std::thread::sleep;
+    /// use std::time::Duration;
+    ///
+    /// let tx = Transaction::new(
+    ///     Hash("0x123".to_string()),
+    ///     Address("0xabc".to_string()),
+    ///     Some(Address("0xdef".to_string())),
+    ///     1000,
+    ///     50,
+    ///     21000,
+    ///     5,
+    ///     vec![],
+    /// );
+    ///
+    /// sleep(Duration::from_secs(1));
+    /// assert!(tx.age_in_seconds() >= 1);
+    /// ```
+    pub fn age_in_seconds(&self) -> u64 {
+        common::utils::current_timestamp().saturating_sub(self.timestamp)
+    }
+
+    /// Creates a new transaction
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blockchain_core::models::Transaction;
+    /// use common::types::{Address, Hash};
+    ///
+    /// let tx = Transaction::new(
+    ///     Hash("0x123".to_string()),
+    ///     Address("0xabc".to_string()),
+    ///     Some(Address("0xdef".to_string())),
+    ///     1000,
+    ///     50,
+    ///     21000,
+    ///     5,
+    ///     vec![1, 2, 3],
+    /// );
+    ///
+    /// assert_eq!(tx.value, 1000);
+    /// ```
+    pub fn new(
+        hash: Hash,
+        from: Address,
+        to: Option<Address>,
+        value: u64,
+        gas_price: u64,
+        gas_limit: u64,
+        nonce: u64,
+        data: Vec<u8>,
+    ) -> Self {
+        Self {
+            hash,
+            from,
+            to,
+            value,
+            gas_price,
+            gas_limit,
+            nonce,
+            data,
+            timestamp: common::utils::current_timestamp(),
+            signature: None,
+        }
+    }
+
+    /// Attaches ECDSA signature components to this transaction
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blockchain_core::models::{SignatureComponents, Transaction};
+    /// use common::types::{Address, Hash};
+    ///
+    /// let tx = Transaction::new(
+    ///     Hash("0x123".to_string()),
+    ///     Address("0xabc".to_string()),
+    ///     Some(Address("0xdef".to_string())),
+    ///     1000,
+    ///     50,
+    ///     21000,
+    ///     5,
+    ///     vec![],
+    /// ).with_signature(SignatureComponents { r: [1u8; 32], s: [2u8; 32], v: 0 });
+    ///
+    /// assert!(tx.signature.is_some());
+    /// ```
+    pub fn with_signature(mut self, signature: SignatureComponents) -> Self {
+        self.signature = Some(signature);
+        self
+    }
+
+    /// Computes the hash that this transaction's signature is expected to
+    /// cover
+    ///
+    /// This is a simplified stand-in for full RLP transaction encoding: it
+    /// hashes `from`, `to` (when present), `value`, `gas_price`,
+    /// `gas_limit`, `nonce` and `data` with keccak256, leaving out `hash`,
+    /// `timestamp` and `signature`, which is enough to support signature recovery and
+    /// verification without a full RLP encoder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blockchain_core::models::Transaction;
+    /// use common::types::{Address, Hash};
+    ///
+    /// let tx = Transaction::new(
+    ///     Hash("0x123".to_string()),
+    ///     Address("0xabc".to_string()),
+    ///     Some(Address("0xdef".to_string())),
+    ///     1000,
+    ///     50,
+    ///     21000,
+    ///     5,
+    ///     vec![],
+    /// );
+    ///
+    /// assert_eq!(tx.signing_hash().len(), 32);
+    /// ```
+    pub fn signing_hash(&self) -> [u8; 32] {
+        let mut hasher = Keccak256::new();
+        hasher.update(self.from.0.as_bytes());
+        if let Some(to) = &self.to {
+            hasher.update(to.0.as_bytes());
+        }
+        hasher.update(self.value.to_be_bytes());
+        hasher.update(self.gas_price.to_be_bytes());
+        hasher.update(self.gas_limit.to_be_bytes());
+        hasher.update(self.nonce.to_be_bytes());
+        hasher.update(&self.data);
+        hasher.finalize().into()
+    }
+}
+
+/// Represents a smart contract on the blockchain
+///
+/// # Examples
+///
+/// ```
+/// use blockchain_core::models::SmartContract;
+/// use common::types::Address;
+/// use std::collections::HashMap;
+///
+/// let mut contract = SmartContract {
+///     address: Address("0x789".to_string()),
+///     bytecode: vec![0, 1, 2],
+///     creator: Address("0xabc".to_string()),
+///     creation_tx: "0x123".to_string(),
+///     storage: HashMap::new(),
+///     timestamp: 1645484400,
+/// };
+///
+/// // Add some storage values
+/// contract.storage.insert("balance".to_string(), vec![0, 0, 0, 42]);
+///
+/// assert!(contract.storage.contains_key("balance"));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmartContract {
+    /// Contract address
+    pub address: Address,
+    /// Contract bytecode
+    pub bytecode: Vec<u8>,
+    /// Contract creator address
+    pub creator: Address,
+    /// Creation transaction hash
+    pub creation_tx: String,
+    /// Contract storage
+    pub storage: HashMap<String, Vec<u8>>,
+    /// Contract creation timestamp
+    pub timestamp: u64,
+}
+
+impl SmartContract {
+    /// Creates a new smart contract instance
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blockchain_core::models::SmartContract;
+    /// use common::types::Address;
+    ///
+    /// let contract = SmartContract::new(
+    ///     Address("0x789".to_string()),
+    ///     vec![1, 2, 3],
+    ///     Address("0xabc".to_string()),
+    ///     "0x123".to_string(),
+    /// );
+    ///
+    /// assert_eq!(contract.bytecode, vec![1, 2, 3]);
+    /// ```
+    pub fn new(
+        address: Address,
+        bytecode: Vec<u8>,
+        creator: Address,
+        creation_tx: String,
+    ) -> Self {
+        Self {
+            address,
+            bytecode,
+            creator,
+            creation_tx,
+            storage: HashMap::new(),
+            timestamp: common::utils::current_timestamp(),
+        }
+    }
+
+    /// Checks if the contract has a specific storage key
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blockchain_core::models::SmartContract;
+    /// use common::types::Address;
+    ///
+    /// let mut contract = SmartContract::new(
+    ///     Address("0x789".to_string()),
+    ///     vec![],
+    ///     Address("0xabc".to_string()),
+    ///     "0x123".to_string(),
+    /// );
+    ///
+    /// contract.storage.insert("balance".to_string(), vec![0, 0, 0, 42]);
+    /// assert!(contract.has_storage("balance"));
+    /// assert!(!contract.has_storage("nonexistent"));
+    /// ```
+    pub fn has_storage(&self, key: &str) -> bool {
+        self.storage.contains_key(key)
+    }
+
+    /// Returns the contract age in seconds
+    pub fn age_in_seconds(&self) -> u64 {
+        common::utils::current_timestamp().saturating_sub(self.timestamp)
+    }
+
+    /// Returns the size of the contract bytecode in bytes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blockchain_core::models::SmartContract;
+    /// use common::types::Address;
+    ///
+    /// let contract = SmartContract::new(
+    ///     Address("0x789".to_string()),
+    ///     vec![1, 2, 3, 4, 5],
+    ///     Address("0xabc".to_string()),
+    ///     "0x123".to_string(),
+    /// );
+    ///
+    /// assert_eq!(contract.bytecode_size(), 5);
+    /// ```
+    pub fn bytecode_size(&self) -> usize {
+        self.bytecode.len()
+    }
+}
+
+/// Result of a security analysis
+///
+/// # Examples
+///
+/// ```
+/// use blockchain_core::models::SecurityAnalysis;
+/// use common::types::RiskLevel;
+///
+/// let analysis = SecurityAnalysis {
+///     risk_level: RiskLevel::High,
+///     risk_score: 20,
+///     findings: vec!["Reentrancy vulnerability detected".to_string()],
+///     metadata: Default::default(),
+/// };
+///
+/// assert_eq!(analysis.risk_level, RiskLevel::High);
+/// assert!(!analysis.findings.is_empty());
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecurityAnalysis {
+    /// Overall risk level
+    pub risk_level: common::types::RiskLevel,
+    /// Aggregate weighted risk score on a 0-100 scale, derived from every
+    /// finding's severity (see `security_analyzer::analysis::score_findings`)
+    pub risk_score: u8,
+    /// List of security findings
+    pub findings: Vec<String>,
+    /// Additional metadata about the analysis
+    pub metadata: HashMap<String, String>,
+}
+
+/// Builds a bloom filter covering a batch of transactions' `from`, `to`,
+/// and `hash` fields, so callers can cheaply test whether a transaction
+/// involving a given address or hash might be in the batch before running
+/// full analysis over it
+///
+/// # Examples
+///
+/// ```
+/// use blockchain_core::models::{bloom_from_transactions, Transaction};
+/// use common::types::{Address, Hash};
+///
+/// let tx = Transaction::new(
+///     Hash("0x123".to_string()),
+///     Address("0xabc".to_string()),
+///     Some(Address("0xdef".to_string())),
+///     1000,
+///     50,
+///     21000,
+///     5,
+///     vec![],
+/// );
+///
+/// let bloom = bloom_from_transactions(&[tx]);
+/// assert!(bloom.contains(b"0xabc"));
+/// assert!(!bloom.contains(b"0xnotinvolved"));
+/// ```
+pub fn bloom_from_transactions(transactions: &[Transaction]) -> Bloom {
+    let mut bloom = Bloom::new();
+    for tx in transactions {
+        bloom.insert(tx.from.0.as_bytes());
+        if let Some(to) = &tx.to {
+            bloom.insert(to.0.as_bytes());
+        }
+        bloom.insert(tx.hash.0.as_bytes());
+    }
+    bloom
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::types::RiskLevel;
+
+    #[test]
+    fn test_transaction_creation() {
+        let tx = Transaction::new(
+            Hash("0x123".to_string()),
+            Address("0xabc".to_string()),
+            Some(Address("0xdef".to_string())),
+            1000,
+            50,
+            21000,
+            5,
+            vec![1, 2, 3],
+        );
+
+        assert_eq!(tx.hash.0, "0x123");
+        assert_eq!(tx.from.0, "0xabc");
+        assert_eq!(tx.to.unwrap().0, "0xdef");
+        assert_eq!(tx.value, 1000);
+        assert_eq!(tx.gas_price, 50);
+        assert_eq!(tx.gas_limit, 21000);
+        assert_eq!(tx.nonce, 5);
+        assert_eq!(tx.data, vec![1, 2, 3]);
+        assert!(tx.timestamp > 0);
+        assert!(tx.signature.is_none());
+    }
+
+    #[test]
+    fn test_transaction_signing_hash_is_stable_and_signature_aware() {
+        let tx = Transaction::new(
+            Hash("0x123".to_string()),
+            Address("0xabc".to_string()),
+            Some(Address("0xdef".to_string())),
+            1000,
+            50,
+            21000,
+            5,
+            vec![1, 2, 3],
+        );
+
+        // Signing hash only covers the unsigned fields, so it's stable
+        // across calls and unaffected by attaching a signature.
+        let hash_before = tx.signing_hash();
+        let signed = tx.with_signature(SignatureComponents {
+            r: [1u8; 32],
+            s: [2u8; 32],
+            v: 0,
+        });
+        assert_eq!(hash_before, signed.signing_hash());
+        assert!(signed.signature.is_some());
+    }
+
+    #[test]
+    fn test_smart_contract() {
+        let contract = SmartContract {
+            address: Address("0x789".to_string()),
+            bytecode: vec![0, 1, 2],
+            creator: Address("0xabc".to_string()),
+            creation_tx: "0x123".to_string(),
+            storage: HashMap::new(),
+            timestamp: common::utils::current_timestamp(),
+        };
+
+        assert_eq!(contract.address.0, "0x789");
+        assert_eq!(contract.bytecode, vec![0, 1, 2]);
+        assert_eq!(contract.creator.0, "0xabc");
+    }
+
+    #[test]
+    fn test_security_analysis() {
+        let findings = vec!["Vulnerability found".to_string()];
+        let mut metadata = HashMap::new();
+        metadata.insert("scanner".to_string(), "test".to_string());
+
+        let analysis = SecurityAnalysis {
+            risk_level: RiskLevel::High,
+            risk_score: 20,
+            findings: findings.clone(),
+            metadata,
+        };
+
+        assert_eq!(analysis.risk_level, RiskLevel::High);
+        assert_eq!(analysis.findings, findings);
+        assert_eq!(analysis.metadata.get("scanner").unwrap(), "test");
+    }
+
+    #[test]
+    fn test_bloom_from_transactions() {
+        let tx = Transaction::new(
+            Hash("0x123".to_string()),
+            Address("0xabc".to_string()),
+            Some(Address("0xdef".to_string())),
+            1000,
+            50,
+            21000,
+            5,
+            vec![],
+        );
+
+        let bloom = bloom_from_transactions(&[tx]);
+        assert!(bloom.contains(b"0xabc"));
+        assert!(bloom.contains(b"0xdef"));
+        assert!(bloom.contains(b"0x123"));
+        assert!(!bloom.contains(b"0xnotinvolved"));
+    }
+}
@@ -0,0 +1,452 @@
+// blockchain-core/src/multi_node.rs
+//! Resilient access across several heterogeneous RPC endpoints, detecting
+//! each endpoint's underlying node implementation and failing over between
+//! them the way ethers' `Provider` lets callers configure a fallback chain.
+
+use crate::blockchain::{BlockchainDataProvider, BlockchainError};
+use crate::models::{SecurityAnalysis, SmartContract, Transaction};
+use crate::rpc::NodeClientProbe;
+use async_trait::async_trait;
+use common::{
+    error::{Error, Result},
+    types::{Address, Hash, TimeRange},
+};
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
+
+/// The node implementation detected behind an endpoint, identified from its
+/// `web3_clientVersion` string
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeClient {
+    Geth,
+    Erigon,
+    OpenEthereum,
+    Nethermind,
+    Besu,
+    /// Responded, but the client string didn't match a known implementation
+    Unknown,
+}
+
+impl NodeClient {
+    /// Classifies a `web3_clientVersion` string, e.g.
+    /// `"Geth/v1.13.0-stable/linux-amd64/go1.21.0"`
+    pub fn detect(client_version: &str) -> Self {
+        let lower = client_version.to_lowercase();
+        if lower.contains("geth") {
+            NodeClient::Geth
+        } else if lower.contains("erigon") {
+            NodeClient::Erigon
+        } else if lower.contains("openethereum") || lower.contains("parity") {
+            NodeClient::OpenEthereum
+        } else if lower.contains("nethermind") {
+            NodeClient::Nethermind
+        } else if lower.contains("besu") {
+            NodeClient::Besu
+        } else {
+            NodeClient::Unknown
+        }
+    }
+
+    /// Whether this client is known to expose historical/indexed
+    /// transaction queries beyond the bare Ethereum JSON-RPC spec
+    fn has_extended_indexing(self) -> bool {
+        matches!(self, NodeClient::Erigon)
+    }
+}
+
+fn is_failover_error(error: &Error) -> bool {
+    matches!(
+        error.downcast_ref::<BlockchainError>(),
+        Some(BlockchainError::ConnectionError(_)) | Some(BlockchainError::RPCError(_))
+    )
+}
+
+#[derive(Default)]
+struct EndpointHealth {
+    consecutive_failures: u32,
+    cooldown_until: Option<Instant>,
+}
+
+struct NodeEndpoint<P> {
+    provider: P,
+    client: Mutex<Option<NodeClient>>,
+    health: Mutex<EndpointHealth>,
+}
+
+const DEFAULT_MAX_CONSECUTIVE_FAILURES: u32 = 3;
+const DEFAULT_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// Aggregates several endpoints of potentially different node
+/// implementations, routing calls to the first configured (primary)
+/// endpoint and failing over to the next healthy one on a transient
+/// `BlockchainError::ConnectionError`/`RPCError`
+///
+/// Each endpoint's node implementation is detected lazily, on first use,
+/// via [`NodeClientProbe::client_version`], and stored as a [`NodeClient`].
+/// Methods whose best request path depends on the node (e.g. historical
+/// queries that only some clients index) consult that per-endpoint
+/// `NodeClient` to prefer a capable endpoint before falling back to plain
+/// primary-then-failover order.
+///
+/// An endpoint that fails `max_consecutive_failures` times in a row is put
+/// on cooldown and skipped until it elapses, so a permanently dead endpoint
+/// doesn't add latency to every call.
+pub struct MultiNodeProvider<P> {
+    endpoints: Vec<NodeEndpoint<P>>,
+    max_consecutive_failures: u32,
+    cooldown: Duration,
+}
+
+impl<P: BlockchainDataProvider + NodeClientProbe> MultiNodeProvider<P> {
+    /// Wraps `providers` in configured order, the first being the primary
+    /// endpoint, with the default health policy (3 consecutive failures
+    /// before a 30-second cooldown)
+    pub fn new(providers: Vec<P>) -> Self {
+        Self::with_health_policy(providers, DEFAULT_MAX_CONSECUTIVE_FAILURES, DEFAULT_COOLDOWN)
+    }
+
+    /// Wraps `providers` with a custom failure threshold and cooldown
+    /// duration before a failed endpoint is retried
+    pub fn with_health_policy(providers: Vec<P>, max_consecutive_failures: u32, cooldown: Duration) -> Self {
+        Self {
+            endpoints: providers
+                .into_iter()
+                .map(|provider| NodeEndpoint {
+                    provider,
+                    client: Mutex::new(None),
+                    health: Mutex::new(EndpointHealth::default()),
+                })
+                .collect(),
+            max_consecutive_failures,
+            cooldown,
+        }
+    }
+
+    /// The node implementation detected for endpoint `index`, if it's been
+    /// probed (successfully or not) yet
+    pub fn node_client(&self, index: usize) -> Option<NodeClient> {
+        self.endpoints.get(index).and_then(|endpoint| *endpoint.client.lock().unwrap())
+    }
+
+    /// Whether endpoint `index` is currently eligible to receive calls
+    /// (i.e. not on cooldown after repeated failures)
+    pub fn is_endpoint_healthy(&self, index: usize) -> bool {
+        self.endpoints.get(index).map(|endpoint| Self::is_available(endpoint)).unwrap_or(false)
+    }
+
+    fn is_available(endpoint: &NodeEndpoint<P>) -> bool {
+        match endpoint.health.lock().unwrap().cooldown_until {
+            Some(until) => Instant::now() >= until,
+            None => true,
+        }
+    }
+
+    fn record_success(endpoint: &NodeEndpoint<P>) {
+        let mut health = endpoint.health.lock().unwrap();
+        health.consecutive_failures = 0;
+        health.cooldown_until = None;
+    }
+
+    fn record_failure(&self, endpoint: &NodeEndpoint<P>) {
+        let mut health = endpoint.health.lock().unwrap();
+        health.consecutive_failures += 1;
+        if health.consecutive_failures >= self.max_consecutive_failures {
+            health.cooldown_until = Some(Instant::now() + self.cooldown);
+        }
+    }
+
+    async fn ensure_probed(&self, index: usize) {
+        let endpoint = &self.endpoints[index];
+        if endpoint.client.lock().unwrap().is_some() {
+            return;
+        }
+        match endpoint.provider.client_version().await {
+            Ok(version) => {
+                let client = NodeClient::detect(&version);
+                debug!(endpoint = index, client = ?client, version, "detected node client");
+                *endpoint.client.lock().unwrap() = Some(client);
+            }
+            Err(error) => {
+                warn!(endpoint = index, %error, "failed to probe node client version");
+                *endpoint.client.lock().unwrap() = Some(NodeClient::Unknown);
+            }
+        }
+    }
+
+    /// Ranks endpoints for `method`: lower is tried first
+    ///
+    /// For ordinary calls every endpoint ranks equally, so the stable sort
+    /// preserves configured (primary-first) order. For calls with
+    /// client-dependent support, a capable endpoint is preferred ahead of
+    /// the usual order.
+    fn capability_rank(&self, index: usize, method: &str) -> u8 {
+        if !matches!(method, "get_transactions_in_range" | "get_address_transactions") {
+            return 0;
+        }
+        match self.node_client(index) {
+            Some(client) if client.has_extended_indexing() => 0,
+            Some(NodeClient::Unknown) | None => 2,
+            Some(_) => 1,
+        }
+    }
+
+    fn endpoint_order(&self, method: &'static str) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.endpoints.len()).collect();
+        order.sort_by_key(|&index| self.capability_rank(index, method));
+        order
+    }
+
+    async fn dispatch<T, F, Fut>(&self, method: &'static str, call: F) -> Result<T>
+    where
+        F: Fn(&P) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut last_error = None;
+
+        for index in self.endpoint_order(method) {
+            let endpoint = &self.endpoints[index];
+            if !Self::is_available(endpoint) {
+                continue;
+            }
+
+            self.ensure_probed(index).await;
+
+            match call(&endpoint.provider).await {
+                Ok(value) => {
+                    Self::record_success(endpoint);
+                    return Ok(value);
+                }
+                Err(error) => {
+                    if !is_failover_error(&error) {
+                        return Err(error);
+                    }
+                    warn!(endpoint = index, method, %error, "endpoint failed, trying next");
+                    self.record_failure(endpoint);
+                    last_error = Some(error);
+                }
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| {
+            Error::Other(Box::new(BlockchainError::ConnectionError("no healthy endpoints available".to_string())))
+        }))
+    }
+}
+
+#[async_trait]
+impl<P: BlockchainDataProvider + NodeClientProbe> BlockchainDataProvider for MultiNodeProvider<P> {
+    async fn get_transaction(&self, hash: &Hash) -> Result<Transaction> {
+        self.dispatch("get_transaction", |provider| {
+            let hash = hash.clone();
+            async move { provider.get_transaction(&hash).await }
+        })
+        .await
+    }
+
+    async fn get_contract(&self, address: &Address) -> Result<SmartContract> {
+        self.dispatch("get_contract", |provider| {
+            let address = address.clone();
+            async move { provider.get_contract(&address).await }
+        })
+        .await
+    }
+
+    async fn get_transactions_in_range(&self, range: TimeRange) -> Result<Vec<Transaction>> {
+        self.dispatch("get_transactions_in_range", move |provider| async move {
+            provider.get_transactions_in_range(range).await
+        })
+        .await
+    }
+
+    async fn get_address_transactions(&self, address: &Address) -> Result<Vec<Transaction>> {
+        self.dispatch("get_address_transactions", |provider| {
+            let address = address.clone();
+            async move { provider.get_address_transactions(&address).await }
+        })
+        .await
+    }
+
+    async fn get_balance(&self, address: &Address) -> Result<u64> {
+        self.dispatch("get_balance", |provider| {
+            let address = address.clone();
+            async move { provider.get_balance(&address).await }
+        })
+        .await
+    }
+
+    async fn get_nonce(&self, address: &Address) -> Result<u64> {
+        self.dispatch("get_nonce", |provider| {
+            let address = address.clone();
+            async move { provider.get_nonce(&address).await }
+        })
+        .await
+    }
+
+    async fn analyze_contract(&self, address: &Address) -> Result<SecurityAnalysis> {
+        self.dispatch("analyze_contract", |provider| {
+            let address = address.clone();
+            async move { provider.analyze_contract(&address).await }
+        })
+        .await
+    }
+
+    async fn get_code(&self, address: &Address) -> Result<Vec<u8>> {
+        self.dispatch("get_code", |provider| {
+            let address = address.clone();
+            async move { provider.get_code(&address).await }
+        })
+        .await
+    }
+
+    async fn get_storage_at(&self, address: &Address, slot: &[u8; 32]) -> Result<[u8; 32]> {
+        self.dispatch("get_storage_at", |provider| {
+            let address = address.clone();
+            let slot = *slot;
+            async move { provider.get_storage_at(&address, &slot).await }
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::types::RiskLevel;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+
+    struct FakeNode {
+        client_version: String,
+        fail_n_times: Arc<AtomicU32>,
+        calls: Arc<AtomicU32>,
+    }
+
+    impl FakeNode {
+        fn healthy(client_version: &str) -> (Self, Arc<AtomicU32>) {
+            Self::failing(client_version, 0)
+        }
+
+        fn failing(client_version: &str, n: u32) -> (Self, Arc<AtomicU32>) {
+            let calls = Arc::new(AtomicU32::new(0));
+            let node = Self {
+                client_version: client_version.to_string(),
+                fail_n_times: Arc::new(AtomicU32::new(n)),
+                calls: Arc::clone(&calls),
+            };
+            (node, calls)
+        }
+    }
+
+    #[async_trait]
+    impl NodeClientProbe for FakeNode {
+        async fn client_version(&self) -> Result<String> {
+            Ok(self.client_version.clone())
+        }
+    }
+
+    #[async_trait]
+    impl BlockchainDataProvider for FakeNode {
+        async fn get_transaction(&self, hash: &Hash) -> Result<Transaction> {
+            Err(Error::NotFound(hash.to_string()))
+        }
+
+        async fn get_contract(&self, address: &Address) -> Result<SmartContract> {
+            Err(Error::Other(Box::new(BlockchainError::ContractNotFound(address.to_string()))))
+        }
+
+        async fn get_transactions_in_range(&self, _range: TimeRange) -> Result<Vec<Transaction>> {
+            Ok(Vec::new())
+        }
+
+        async fn get_address_transactions(&self, _address: &Address) -> Result<Vec<Transaction>> {
+            Ok(Vec::new())
+        }
+
+        async fn get_balance(&self, _address: &Address) -> Result<u64> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let remaining = self.fail_n_times.load(Ordering::SeqCst);
+            if remaining > 0 {
+                self.fail_n_times.fetch_sub(1, Ordering::SeqCst);
+                return Err(Error::Other(Box::new(BlockchainError::ConnectionError("down".to_string()))));
+            }
+            Ok(7)
+        }
+
+        async fn get_nonce(&self, _address: &Address) -> Result<u64> {
+            Ok(0)
+        }
+
+        async fn analyze_contract(&self, _address: &Address) -> Result<SecurityAnalysis> {
+            Ok(SecurityAnalysis { risk_level: RiskLevel::None, risk_score: 0, findings: Vec::new(), metadata: HashMap::new() })
+        }
+
+        async fn get_code(&self, _address: &Address) -> Result<Vec<u8>> {
+            Ok(Vec::new())
+        }
+
+        async fn get_storage_at(&self, _address: &Address, _slot: &[u8; 32]) -> Result<[u8; 32]> {
+            Ok([0u8; 32])
+        }
+    }
+
+    #[tokio::test]
+    async fn test_routes_to_primary_endpoint_when_healthy() {
+        let (primary, primary_calls) = FakeNode::healthy("Geth/v1.13.0");
+        let (secondary, secondary_calls) = FakeNode::healthy("Erigon/2024.01.1");
+        let provider = MultiNodeProvider::new(vec![primary, secondary]);
+
+        let balance = provider.get_balance(&Address("0xabc".to_string())).await.unwrap();
+        assert_eq!(balance, 7);
+        assert_eq!(primary_calls.load(Ordering::SeqCst), 1);
+        assert_eq!(secondary_calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_fails_over_to_next_endpoint_on_connection_error() {
+        let (primary, _) = FakeNode::failing("Geth/v1.13.0", 10);
+        let (secondary, secondary_calls) = FakeNode::healthy("Erigon/2024.01.1");
+        let provider = MultiNodeProvider::new(vec![primary, secondary]);
+
+        let balance = provider.get_balance(&Address("0xabc".to_string())).await.unwrap();
+        assert_eq!(balance, 7);
+        assert_eq!(secondary_calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_detects_node_client_from_version_string() {
+        let (primary, _) = FakeNode::healthy("Geth/v1.13.0-stable/linux-amd64/go1.21.0");
+        let provider = MultiNodeProvider::new(vec![primary]);
+
+        provider.get_balance(&Address("0xabc".to_string())).await.unwrap();
+        assert_eq!(provider.node_client(0), Some(NodeClient::Geth));
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_goes_on_cooldown_after_repeated_failures() {
+        let (primary, _) = FakeNode::failing("Geth/v1.13.0", 10);
+        let (secondary, _) = FakeNode::healthy("Erigon/2024.01.1");
+        let provider = MultiNodeProvider::with_health_policy(vec![primary, secondary], 2, Duration::from_secs(60));
+
+        provider.get_balance(&Address("0xabc".to_string())).await.unwrap();
+        provider.get_balance(&Address("0xabc".to_string())).await.unwrap();
+
+        assert!(!provider.is_endpoint_healthy(0));
+    }
+
+    #[tokio::test]
+    async fn test_extended_indexing_client_is_preferred_for_range_queries() {
+        let (geth, _) = FakeNode::healthy("Geth/v1.13.0");
+        let (erigon, _) = FakeNode::healthy("Erigon/2024.01.1");
+        let provider = MultiNodeProvider::new(vec![geth, erigon]);
+
+        // Probe both endpoints up front so the ranking has client info to use.
+        provider.ensure_probed(0).await;
+        provider.ensure_probed(1).await;
+
+        let order = provider.endpoint_order("get_transactions_in_range");
+        assert_eq!(order.first(), Some(&1));
+    }
+}
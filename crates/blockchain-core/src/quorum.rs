@@ -0,0 +1,404 @@
+// blockchain-core/src/quorum.rs
+//! Aggregates several `BlockchainDataProvider`s behind a single provider,
+//! accepting a result only once enough of them agree
+//!
+//! Unlike `middleware::ProviderMiddleware`, which wraps a single inner
+//! provider, `QuorumProvider` fans a call out to a set of providers
+//! concurrently and only trusts the answer a configurable share of them
+//! returned the same value for — defending against a single faulty or
+//! malicious RPC endpoint.
+
+use crate::blockchain::{BlockchainDataProvider, BlockchainError};
+use crate::models::{SecurityAnalysis, SmartContract, Transaction};
+use async_trait::async_trait;
+use common::{
+    error::{Error, Result},
+    types::{Address, Hash, TimeRange},
+};
+use futures::future::join_all;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+
+/// How much of a `QuorumProvider`'s total configured weight must agree on a
+/// value before it's returned
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuorumPolicy {
+    /// More than half of the total weight must agree
+    Majority,
+    /// At least this much weight must agree
+    AtLeast(u64),
+    /// Every last unit of configured weight must agree
+    All,
+}
+
+impl QuorumPolicy {
+    fn threshold(self, total_weight: u64) -> u64 {
+        match self {
+            QuorumPolicy::Majority => total_weight / 2 + 1,
+            QuorumPolicy::AtLeast(weight) => weight,
+            QuorumPolicy::All => total_weight,
+        }
+    }
+}
+
+struct WeightedProvider {
+    provider: Arc<dyn BlockchainDataProvider>,
+    weight: u64,
+}
+
+/// The classification a single provider's response to a quorum call falls
+/// into, before weights are tallied
+///
+/// Deriving `Serialize` gives `Absent` and `Present(value)` distinct
+/// canonical forms for free, so they're never mistaken for the same answer
+/// when votes are tallied by their serialized representation.
+#[derive(Serialize)]
+enum QuorumOutcome<T> {
+    Present(T),
+    Absent,
+}
+
+fn is_absent(error: &Error) -> bool {
+    matches!(error, Error::NotFound(_))
+        || matches!(
+            error.downcast_ref::<BlockchainError>(),
+            Some(BlockchainError::ContractNotFound(_))
+        )
+}
+
+/// Combines several `BlockchainDataProvider`s into one, accepting a result
+/// only once providers whose combined weight meets `policy`'s threshold
+/// agree on it
+///
+/// For every read, each inner provider is queried concurrently. A provider
+/// erroring with "not found" (e.g. `BlockchainError::ContractNotFound`)
+/// votes for a distinct `Absent` outcome rather than being dropped, since
+/// "no such contract" can itself be the quorum-correct answer; any other
+/// per-provider error is simply dropped from the count. If no single
+/// outcome's accumulated weight reaches the threshold, the call fails with
+/// `BlockchainError::RPCError` describing the disagreement.
+///
+/// # Examples
+///
+/// ```
+/// use blockchain_core::quorum::{QuorumPolicy, QuorumProvider};
+/// use blockchain_core::blockchain::BlockchainDataProvider;
+/// use std::sync::Arc;
+///
+/// # tokio_test::block_on(async {
+/// # struct Empty;
+/// # #[async_trait::async_trait]
+/// # impl BlockchainDataProvider for Empty {
+/// #     async fn get_transaction(&self, hash: &common::types::Hash) -> common::error::Result<blockchain_core::models::Transaction> {
+/// #         Err(common::error::Error::NotFound(hash.to_string()))
+/// #     }
+/// #     async fn get_contract(&self, address: &common::types::Address) -> common::error::Result<blockchain_core::models::SmartContract> {
+/// #         Err(common::error::Error::Other(Box::new(blockchain_core::blockchain::BlockchainError::ContractNotFound(address.to_string()))))
+/// #     }
+/// #     async fn get_transactions_in_range(&self, _range: common::types::TimeRange) -> common::error::Result<Vec<blockchain_core::models::Transaction>> { Ok(vec![]) }
+/// #     async fn get_address_transactions(&self, _address: &common::types::Address) -> common::error::Result<Vec<blockchain_core::models::Transaction>> { Ok(vec![]) }
+/// #     async fn get_balance(&self, _address: &common::types::Address) -> common::error::Result<u64> { Ok(0) }
+/// #     async fn get_nonce(&self, _address: &common::types::Address) -> common::error::Result<u64> { Ok(0) }
+/// #     async fn analyze_contract(&self, _address: &common::types::Address) -> common::error::Result<blockchain_core::models::SecurityAnalysis> {
+/// #         Ok(blockchain_core::models::SecurityAnalysis { risk_level: common::types::RiskLevel::None, risk_score: 0, findings: vec![], metadata: Default::default() })
+/// #     }
+/// #     async fn get_code(&self, _address: &common::types::Address) -> common::error::Result<Vec<u8>> { Ok(vec![]) }
+/// #     async fn get_storage_at(&self, _address: &common::types::Address, _slot: &[u8; 32]) -> common::error::Result<[u8; 32]> { Ok([0u8; 32]) }
+/// # }
+/// let providers: Vec<Arc<dyn BlockchainDataProvider>> = vec![Arc::new(Empty), Arc::new(Empty), Arc::new(Empty)];
+/// let quorum = QuorumProvider::new(providers, QuorumPolicy::Majority);
+///
+/// // All three providers agree the contract is absent, so that's the
+/// // quorum-confirmed answer rather than a "not found" being dropped.
+/// let address = common::types::Address("0xabc".to_string());
+/// assert!(quorum.get_contract(&address).await.is_err());
+/// # })
+/// ```
+pub struct QuorumProvider {
+    providers: Vec<WeightedProvider>,
+    policy: QuorumPolicy,
+}
+
+impl QuorumProvider {
+    /// Combines `providers` with equal weight (1 each) under `policy`
+    pub fn new(providers: Vec<Arc<dyn BlockchainDataProvider>>, policy: QuorumPolicy) -> Self {
+        Self::with_weights(providers.into_iter().map(|provider| (provider, 1)).collect(), policy)
+    }
+
+    /// Combines `providers`, each with its own voting weight, under `policy`
+    pub fn with_weights(providers: Vec<(Arc<dyn BlockchainDataProvider>, u64)>, policy: QuorumPolicy) -> Self {
+        Self {
+            providers: providers
+                .into_iter()
+                .map(|(provider, weight)| WeightedProvider { provider, weight })
+                .collect(),
+            policy,
+        }
+    }
+
+    fn total_weight(&self) -> u64 {
+        self.providers.iter().map(|p| p.weight).sum()
+    }
+
+    /// Queries every inner provider concurrently via `call`, tallies
+    /// providers' responses by their canonical (serialized) form, and
+    /// returns the value whose accumulated weight first meets the quorum
+    /// threshold
+    async fn dispatch<T, F, Fut>(&self, call: F) -> Result<T>
+    where
+        T: Clone + Serialize + Send,
+        F: Fn(Arc<dyn BlockchainDataProvider>) -> Fut,
+        Fut: Future<Output = Result<T>> + Send,
+    {
+        let responses = join_all(self.providers.iter().map(|weighted| {
+            let weight = weighted.weight;
+            let future = call(Arc::clone(&weighted.provider));
+            async move { (weight, future.await) }
+        }))
+        .await;
+
+        let mut votes: HashMap<String, (QuorumOutcome<T>, u64)> = HashMap::new();
+        let mut considered = 0usize;
+        let mut dropped = 0usize;
+
+        for (weight, outcome) in responses {
+            let vote = match outcome {
+                Ok(value) => QuorumOutcome::Present(value),
+                Err(e) if is_absent(&e) => QuorumOutcome::Absent,
+                Err(_) => {
+                    dropped += 1;
+                    continue;
+                }
+            };
+            considered += 1;
+            let key = serde_json::to_string(&vote).unwrap_or_default();
+            votes.entry(key).or_insert_with(|| (vote, 0)).1 += weight;
+        }
+
+        let threshold = self.policy.threshold(self.total_weight());
+        let winner = votes.into_values().find(|(_, weight)| *weight >= threshold).map(|(outcome, _)| outcome);
+
+        match winner {
+            Some(QuorumOutcome::Present(value)) => Ok(value),
+            Some(QuorumOutcome::Absent) => Err(Error::Other(Box::new(BlockchainError::ContractNotFound(
+                "quorum-confirmed absent across providers".to_string(),
+            )))),
+            None => Err(Error::Other(Box::new(BlockchainError::RPCError(format!(
+                "no quorum reached ({}/{} weight required): {} providers responded, {} dropped",
+                threshold,
+                self.total_weight(),
+                considered,
+                dropped
+            ))))),
+        }
+    }
+}
+
+#[async_trait]
+impl BlockchainDataProvider for QuorumProvider {
+    async fn get_transaction(&self, hash: &Hash) -> Result<Transaction> {
+        self.dispatch(|provider| {
+            let hash = hash.clone();
+            async move { provider.get_transaction(&hash).await }
+        })
+        .await
+    }
+
+    async fn get_contract(&self, address: &Address) -> Result<SmartContract> {
+        self.dispatch(|provider| {
+            let address = address.clone();
+            async move { provider.get_contract(&address).await }
+        })
+        .await
+    }
+
+    async fn get_transactions_in_range(&self, range: TimeRange) -> Result<Vec<Transaction>> {
+        self.dispatch(move |provider| async move { provider.get_transactions_in_range(range).await }).await
+    }
+
+    async fn get_address_transactions(&self, address: &Address) -> Result<Vec<Transaction>> {
+        self.dispatch(|provider| {
+            let address = address.clone();
+            async move { provider.get_address_transactions(&address).await }
+        })
+        .await
+    }
+
+    async fn get_balance(&self, address: &Address) -> Result<u64> {
+        self.dispatch(|provider| {
+            let address = address.clone();
+            async move { provider.get_balance(&address).await }
+        })
+        .await
+    }
+
+    async fn get_nonce(&self, address: &Address) -> Result<u64> {
+        self.dispatch(|provider| {
+            let address = address.clone();
+            async move { provider.get_nonce(&address).await }
+        })
+        .await
+    }
+
+    async fn analyze_contract(&self, address: &Address) -> Result<SecurityAnalysis> {
+        self.dispatch(|provider| {
+            let address = address.clone();
+            async move { provider.analyze_contract(&address).await }
+        })
+        .await
+    }
+
+    async fn get_code(&self, address: &Address) -> Result<Vec<u8>> {
+        self.dispatch(|provider| {
+            let address = address.clone();
+            async move { provider.get_code(&address).await }
+        })
+        .await
+    }
+
+    async fn get_storage_at(&self, address: &Address, slot: &[u8; 32]) -> Result<[u8; 32]> {
+        self.dispatch(|provider| {
+            let address = address.clone();
+            let slot = *slot;
+            async move { provider.get_storage_at(&address, &slot).await }
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::types::RiskLevel;
+
+    struct FixedProvider {
+        balance: Result<u64>,
+    }
+
+    #[async_trait]
+    impl BlockchainDataProvider for FixedProvider {
+        async fn get_transaction(&self, hash: &Hash) -> Result<Transaction> {
+            Err(Error::NotFound(hash.to_string()))
+        }
+
+        async fn get_contract(&self, address: &Address) -> Result<SmartContract> {
+            Err(Error::Other(Box::new(BlockchainError::ContractNotFound(address.to_string()))))
+        }
+
+        async fn get_transactions_in_range(&self, _range: TimeRange) -> Result<Vec<Transaction>> {
+            Ok(Vec::new())
+        }
+
+        async fn get_address_transactions(&self, _address: &Address) -> Result<Vec<Transaction>> {
+            Ok(Vec::new())
+        }
+
+        async fn get_balance(&self, _address: &Address) -> Result<u64> {
+            match &self.balance {
+                Ok(balance) => Ok(*balance),
+                Err(e) => Err(Error::Internal(e.to_string())),
+            }
+        }
+
+        async fn get_nonce(&self, _address: &Address) -> Result<u64> {
+            Ok(0)
+        }
+
+        async fn analyze_contract(&self, _address: &Address) -> Result<SecurityAnalysis> {
+            Ok(SecurityAnalysis {
+                risk_level: RiskLevel::None,
+                risk_score: 0,
+                findings: Vec::new(),
+                metadata: HashMap::new(),
+            })
+        }
+
+        async fn get_code(&self, _address: &Address) -> Result<Vec<u8>> {
+            Ok(Vec::new())
+        }
+
+        async fn get_storage_at(&self, _address: &Address, _slot: &[u8; 32]) -> Result<[u8; 32]> {
+            Ok([0u8; 32])
+        }
+    }
+
+    fn agreeing(balance: u64, n: usize) -> Vec<Arc<dyn BlockchainDataProvider>> {
+        (0..n)
+            .map(|_| Arc::new(FixedProvider { balance: Ok(balance) }) as Arc<dyn BlockchainDataProvider>)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_majority_agreement_wins() {
+        let mut providers = agreeing(100, 2);
+        providers.push(Arc::new(FixedProvider { balance: Ok(999) }));
+        let quorum = QuorumProvider::new(providers, QuorumPolicy::Majority);
+
+        let balance = quorum.get_balance(&Address("0xabc".to_string())).await.unwrap();
+        assert_eq!(balance, 100);
+    }
+
+    #[tokio::test]
+    async fn test_no_quorum_returns_rpc_error() {
+        let providers: Vec<Arc<dyn BlockchainDataProvider>> = vec![
+            Arc::new(FixedProvider { balance: Ok(1) }),
+            Arc::new(FixedProvider { balance: Ok(2) }),
+            Arc::new(FixedProvider { balance: Ok(3) }),
+        ];
+        let quorum = QuorumProvider::new(providers, QuorumPolicy::Majority);
+
+        let result = quorum.get_balance(&Address("0xabc".to_string())).await;
+        assert!(result.is_err());
+        assert!(matches!(
+            result.unwrap_err().downcast_ref::<BlockchainError>(),
+            Some(BlockchainError::RPCError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_all_policy_requires_every_provider_to_agree() {
+        let mut providers = agreeing(100, 2);
+        providers.push(Arc::new(FixedProvider { balance: Ok(999) }));
+        let quorum = QuorumProvider::new(providers, QuorumPolicy::All);
+
+        let result = quorum.get_balance(&Address("0xabc".to_string())).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_dropped_errors_do_not_prevent_quorum() {
+        let mut providers = agreeing(100, 2);
+        providers.push(Arc::new(FixedProvider { balance: Err(Error::Internal("boom".to_string())) }));
+        let quorum = QuorumProvider::new(providers, QuorumPolicy::Majority);
+
+        let balance = quorum.get_balance(&Address("0xabc".to_string())).await.unwrap();
+        assert_eq!(balance, 100);
+    }
+
+    #[tokio::test]
+    async fn test_not_found_forms_its_own_quorum() {
+        let providers: Vec<Arc<dyn BlockchainDataProvider>> =
+            vec![Arc::new(FixedProvider { balance: Ok(0) }), Arc::new(FixedProvider { balance: Ok(0) })];
+        let quorum = QuorumProvider::new(providers, QuorumPolicy::Majority);
+
+        let result = quorum.get_contract(&Address("0xabc".to_string())).await;
+        assert!(matches!(
+            result.unwrap_err().downcast_ref::<BlockchainError>(),
+            Some(BlockchainError::ContractNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_weighted_provider_can_outvote_a_majority_of_providers() {
+        let light = agreeing(1, 2);
+        let heavy: Arc<dyn BlockchainDataProvider> = Arc::new(FixedProvider { balance: Ok(2) });
+        let weighted: Vec<(Arc<dyn BlockchainDataProvider>, u64)> =
+            light.into_iter().map(|p| (p, 1)).chain(std::iter::once((heavy, 10))).collect();
+        let quorum = QuorumProvider::with_weights(weighted, QuorumPolicy::Majority);
+
+        let balance = quorum.get_balance(&Address("0xabc".to_string())).await.unwrap();
+        assert_eq!(balance, 2);
+    }
+}
@@ -0,0 +1,430 @@
+// blockchain-core/src/rpc.rs
+//! `BlockchainDataProvider` implementations backed by a live Ethereum-style
+//! JSON-RPC 2.0 endpoint, reachable either over HTTP or over a Unix domain
+//! socket (IPC) the way `geth`/`erigon`-style clients expose it.
+//!
+//! Both transports speak the same JSON-RPC request/response shape; only how
+//! the request bytes reach the node differs, so [`JsonRpcProvider`] is
+//! generic over a [`JsonRpcTransport`] and implements
+//! [`BlockchainDataProvider`] exactly once.
+
+use crate::blockchain::{BlockchainDataProvider, BlockchainError};
+use crate::models::{SecurityAnalysis, SmartContract, Transaction};
+use async_trait::async_trait;
+use common::{
+    error::{Error, Result},
+    types::{Address, Hash, TimeRange},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tokio::io::{AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::UnixStream;
+use tracing::{debug, instrument};
+
+/// Transport-level concern: deliver a JSON-RPC request body and return the
+/// raw JSON-RPC response body
+///
+/// Implementations only need to know how to move bytes to and from the
+/// node; request construction and response interpretation live in
+/// [`JsonRpcProvider`].
+#[async_trait]
+pub trait JsonRpcTransport: Send + Sync {
+    /// Sends `request` (a serialized JSON-RPC 2.0 request object) and
+    /// returns the node's raw JSON-RPC response object
+    async fn send(&self, request: Value) -> Result<Value>;
+}
+
+/// Speaks JSON-RPC 2.0 over a plain HTTP POST, as exposed by an Ethereum
+/// client's `--http` endpoint
+pub struct HttpTransport {
+    endpoint: String,
+    client: reqwest::Client,
+}
+
+impl HttpTransport {
+    /// Creates a transport that POSTs JSON-RPC requests to `endpoint`
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl JsonRpcTransport for HttpTransport {
+    async fn send(&self, request: Value) -> Result<Value> {
+        let response = self
+            .client
+            .post(&self.endpoint)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| Error::Other(Box::new(BlockchainError::ConnectionError(e.to_string()))))?;
+
+        response
+            .json::<Value>()
+            .await
+            .map_err(|e| Error::Other(Box::new(BlockchainError::RPCError(e.to_string()))))
+    }
+}
+
+/// Speaks JSON-RPC 2.0 over a Unix domain socket, as exposed by an Ethereum
+/// client's IPC endpoint (`geth.ipc`), framing each request/response as a
+/// single newline-delimited JSON document
+pub struct IpcTransport {
+    socket_path: String,
+}
+
+impl IpcTransport {
+    /// Creates a transport that connects to the Unix domain socket at
+    /// `socket_path` for each call
+    pub fn new(socket_path: impl Into<String>) -> Self {
+        Self {
+            socket_path: socket_path.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl JsonRpcTransport for IpcTransport {
+    async fn send(&self, request: Value) -> Result<Value> {
+        let mut stream = UnixStream::connect(&self.socket_path)
+            .await
+            .map_err(|e| Error::Other(Box::new(BlockchainError::ConnectionError(e.to_string()))))?;
+
+        let mut payload = serde_json::to_vec(&request)
+            .map_err(|e| Error::Other(Box::new(BlockchainError::RPCError(e.to_string()))))?;
+        payload.push(b'\n');
+        stream
+            .write_all(&payload)
+            .await
+            .map_err(|e| Error::Other(Box::new(BlockchainError::ConnectionError(e.to_string()))))?;
+
+        let mut reader = BufReader::new(&mut stream);
+        let mut line = Vec::new();
+        loop {
+            let mut byte = [0u8; 1];
+            let n = reader
+                .read(&mut byte)
+                .await
+                .map_err(|e| Error::Other(Box::new(BlockchainError::ConnectionError(e.to_string()))))?;
+            if n == 0 || byte[0] == b'\n' {
+                break;
+            }
+            line.push(byte[0]);
+        }
+
+        serde_json::from_slice(&line)
+            .map_err(|e| Error::Other(Box::new(BlockchainError::RPCError(e.to_string()))))
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct JsonRpcError {
+    code: i64,
+    message: String,
+}
+
+/// A `BlockchainDataProvider` backed by a live JSON-RPC 2.0 endpoint
+///
+/// Generic over the transport so the same request-building and
+/// response-parsing logic serves both [`HttpTransport`] and
+/// [`IpcTransport`]; see [`HttpJsonRpcProvider`] and [`IpcJsonRpcProvider`]
+/// for the concrete aliases most callers want.
+pub struct JsonRpcProvider<T: JsonRpcTransport> {
+    transport: T,
+}
+
+impl<T: JsonRpcTransport> JsonRpcProvider<T> {
+    /// Creates a provider that issues requests over `transport`
+    pub fn new(transport: T) -> Self {
+        Self { transport }
+    }
+
+    /// Issues a single JSON-RPC 2.0 call and returns its `result` field,
+    /// mapping a JSON-RPC error response to `BlockchainError::RPCError`
+    async fn call(&self, method: &str, params: Value) -> Result<Value> {
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+
+        debug!("Issuing JSON-RPC call {}", method);
+        let response = self.transport.send(request).await?;
+
+        if let Some(error) = response.get("error") {
+            let error: JsonRpcError = serde_json::from_value(error.clone())
+                .unwrap_or(JsonRpcError { code: 0, message: error.to_string() });
+            return Err(Error::Other(Box::new(BlockchainError::RPCError(format!(
+                "{} ({}): {}",
+                method, error.code, error.message
+            )))));
+        }
+
+        response
+            .get("result")
+            .cloned()
+            .ok_or_else(|| Error::Other(Box::new(BlockchainError::RPCError(format!(
+                "{} response had no result field",
+                method
+            )))))
+    }
+
+    fn parse_hex_quantity(value: &Value, method: &str) -> Result<u64> {
+        let hex_str = value.as_str().ok_or_else(|| {
+            Error::Other(Box::new(BlockchainError::RPCError(format!(
+                "{} result was not a hex string",
+                method
+            ))))
+        })?;
+        u64::from_str_radix(hex_str.trim_start_matches("0x"), 16)
+            .map_err(|e| Error::Other(Box::new(BlockchainError::RPCError(format!("{}: {}", method, e)))))
+    }
+
+    fn parse_hex_bytes(value: &Value, method: &str) -> Result<Vec<u8>> {
+        let hex_str = value.as_str().ok_or_else(|| {
+            Error::Other(Box::new(BlockchainError::RPCError(format!(
+                "{} result was not a hex string",
+                method
+            ))))
+        })?;
+        common::utils::hex_to_bytes(hex_str)
+            .map_err(|e| Error::Other(Box::new(BlockchainError::RPCError(format!("{}: {}", method, e)))))
+    }
+}
+
+#[async_trait]
+impl<T: JsonRpcTransport> BlockchainDataProvider for JsonRpcProvider<T> {
+    async fn get_transaction(&self, hash: &Hash) -> Result<Transaction> {
+        let result = self.call("eth_getTransactionByHash", json!([hash.0])).await?;
+        if result.is_null() {
+            return Err(Error::NotFound(format!("transaction {} not found", hash)));
+        }
+
+        let from = result
+            .get("from")
+            .and_then(Value::as_str)
+            .ok_or_else(|| Error::Other(Box::new(BlockchainError::RPCError("missing from".to_string()))))?;
+        let to = result.get("to").and_then(Value::as_str).map(|s| Address(s.to_string()));
+        let value = Self::parse_hex_quantity(result.get("value").unwrap_or(&Value::Null), "eth_getTransactionByHash")?;
+        let gas_price = Self::parse_hex_quantity(result.get("gasPrice").unwrap_or(&Value::Null), "eth_getTransactionByHash")?;
+        let gas_limit = Self::parse_hex_quantity(result.get("gas").unwrap_or(&Value::Null), "eth_getTransactionByHash")?;
+        let nonce = Self::parse_hex_quantity(result.get("nonce").unwrap_or(&Value::Null), "eth_getTransactionByHash")?;
+        let data = result
+            .get("input")
+            .and_then(Value::as_str)
+            .map(common::utils::hex_to_bytes)
+            .transpose()
+            .map_err(|e| Error::Other(Box::new(BlockchainError::RPCError(e))))?
+            .unwrap_or_default();
+
+        Ok(Transaction::new(
+            hash.clone(),
+            Address(from.to_string()),
+            to,
+            value,
+            gas_price,
+            gas_limit,
+            nonce,
+            data,
+        ))
+    }
+
+    async fn get_contract(&self, address: &Address) -> Result<SmartContract> {
+        let bytecode = self.get_code(address).await?;
+        if bytecode.is_empty() {
+            return Err(Error::Other(Box::new(BlockchainError::ContractNotFound(address.0.clone()))));
+        }
+
+        Ok(SmartContract::new(
+            address.clone(),
+            bytecode,
+            Address(String::new()),
+            String::new(),
+        ))
+    }
+
+    async fn get_transactions_in_range(&self, _range: TimeRange) -> Result<Vec<Transaction>> {
+        Err(Error::Other(Box::new(BlockchainError::RPCError(
+            "eth_getTransactionByHash has no range query; use an indexer".to_string(),
+        ))))
+    }
+
+    async fn get_address_transactions(&self, _address: &Address) -> Result<Vec<Transaction>> {
+        Err(Error::Other(Box::new(BlockchainError::RPCError(
+            "node JSON-RPC has no address-indexed transaction query; use an indexer".to_string(),
+        ))))
+    }
+
+    async fn get_balance(&self, address: &Address) -> Result<u64> {
+        let result = self.call("eth_getBalance", json!([address.0, "latest"])).await?;
+        Self::parse_hex_quantity(&result, "eth_getBalance")
+    }
+
+    async fn get_nonce(&self, address: &Address) -> Result<u64> {
+        let result = self.call("eth_getTransactionCount", json!([address.0, "latest"])).await?;
+        Self::parse_hex_quantity(&result, "eth_getTransactionCount")
+    }
+
+    async fn analyze_contract(&self, _address: &Address) -> Result<SecurityAnalysis> {
+        Err(Error::Other(Box::new(BlockchainError::RPCError(
+            "analyze_contract is performed by SecurityAnalyzer, not the data provider".to_string(),
+        ))))
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn get_code(&self, address: &Address) -> Result<Vec<u8>> {
+        let result = self.call("eth_getCode", json!([address.0, "latest"])).await?;
+        Self::parse_hex_bytes(&result, "eth_getCode")
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn get_storage_at(&self, address: &Address, slot: &[u8; 32]) -> Result<[u8; 32]> {
+        let slot_hex = common::utils::bytes_to_hex(slot);
+        let result = self.call("eth_getStorageAt", json!([address.0, slot_hex, "latest"])).await?;
+        let bytes = Self::parse_hex_bytes(&result, "eth_getStorageAt")?;
+
+        let mut padded = [0u8; 32];
+        if bytes.len() > 32 {
+            return Err(Error::Other(Box::new(BlockchainError::RPCError(
+                "eth_getStorageAt returned more than 32 bytes".to_string(),
+            ))));
+        }
+        padded[32 - bytes.len()..].copy_from_slice(&bytes);
+        Ok(padded)
+    }
+}
+
+/// Lets a caller detect which node implementation is behind a provider,
+/// without committing every `BlockchainDataProvider` to exposing it
+///
+/// Implemented by [`JsonRpcProvider`] via the standard `web3_clientVersion`
+/// call; see `blockchain_core::multi_node::NodeClient` for turning the
+/// returned string into a client identity.
+#[async_trait]
+pub trait NodeClientProbe: Send + Sync {
+    /// Returns the node's `web3_clientVersion` identifier string, e.g.
+    /// `"Geth/v1.13.0-stable/linux-amd64/go1.21.0"`
+    async fn client_version(&self) -> Result<String>;
+}
+
+#[async_trait]
+impl<T: JsonRpcTransport> NodeClientProbe for JsonRpcProvider<T> {
+    async fn client_version(&self) -> Result<String> {
+        let result = self.call("web3_clientVersion", json!([])).await?;
+        result.as_str().map(str::to_string).ok_or_else(|| {
+            Error::Other(Box::new(BlockchainError::RPCError(
+                "web3_clientVersion result was not a string".to_string(),
+            )))
+        })
+    }
+}
+
+/// A `BlockchainDataProvider` that talks to a node over HTTP JSON-RPC
+pub type HttpJsonRpcProvider = JsonRpcProvider<HttpTransport>;
+
+impl HttpJsonRpcProvider {
+    /// Creates a provider that POSTs JSON-RPC requests to `endpoint`
+    pub fn http(endpoint: impl Into<String>) -> Self {
+        Self::new(HttpTransport::new(endpoint))
+    }
+}
+
+/// A `BlockchainDataProvider` that talks to a node over its IPC socket
+pub type IpcJsonRpcProvider = JsonRpcProvider<IpcTransport>;
+
+impl IpcJsonRpcProvider {
+    /// Creates a provider that connects to the Unix domain socket at
+    /// `socket_path`
+    pub fn ipc(socket_path: impl Into<String>) -> Self {
+        Self::new(IpcTransport::new(socket_path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    struct MockTransport {
+        response: Value,
+        last_request: Mutex<Option<Value>>,
+    }
+
+    #[async_trait]
+    impl JsonRpcTransport for MockTransport {
+        async fn send(&self, request: Value) -> Result<Value> {
+            *self.last_request.lock().unwrap() = Some(request);
+            Ok(self.response.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_code_parses_hex_result() {
+        let transport = MockTransport {
+            response: json!({"jsonrpc": "2.0", "id": 1, "result": "0x6001"}),
+            last_request: Mutex::new(None),
+        };
+        let provider = JsonRpcProvider::new(transport);
+
+        let code = provider
+            .get_code(&Address("0xabc".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(code, vec![0x60, 0x01]);
+
+        let request = provider.transport.last_request.lock().unwrap().clone().unwrap();
+        assert_eq!(request["method"], "eth_getCode");
+    }
+
+    #[tokio::test]
+    async fn test_get_storage_at_pads_short_results() {
+        let transport = MockTransport {
+            response: json!({"jsonrpc": "2.0", "id": 1, "result": "0x2a"}),
+            last_request: Mutex::new(None),
+        };
+        let provider = JsonRpcProvider::new(transport);
+
+        let slot = [0u8; 32];
+        let value = provider
+            .get_storage_at(&Address("0xabc".to_string()), &slot)
+            .await
+            .unwrap();
+        assert_eq!(value[31], 0x2a);
+        assert_eq!(&value[..31], &[0u8; 31]);
+    }
+
+    #[tokio::test]
+    async fn test_call_surfaces_json_rpc_error() {
+        let transport = MockTransport {
+            response: json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "error": {"code": -32000, "message": "execution reverted"}
+            }),
+            last_request: Mutex::new(None),
+        };
+        let provider = JsonRpcProvider::new(transport);
+
+        let result = provider.get_balance(&Address("0xabc".to_string())).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_client_version_returns_web3_client_version_result() {
+        let transport = MockTransport {
+            response: json!({"jsonrpc": "2.0", "id": 1, "result": "Geth/v1.13.0-stable/linux-amd64/go1.21.0"}),
+            last_request: Mutex::new(None),
+        };
+        let provider = JsonRpcProvider::new(transport);
+
+        let version = provider.client_version().await.unwrap();
+        assert_eq!(version, "Geth/v1.13.0-stable/linux-amd64/go1.21.0");
+
+        let request = provider.transport.last_request.lock().unwrap().clone().unwrap();
+        assert_eq!(request["method"], "web3_clientVersion");
+    }
+}
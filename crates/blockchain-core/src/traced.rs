@@ -0,0 +1,278 @@
+// blockchain-core/src/traced.rs
+//! A `ProviderMiddleware` layer that emits a `tracing` span per RPC call,
+//! a debug line as each call is dispatched, and a warn/error line when a
+//! call fails, similar to how Fortuna wraps its
+//! chain clients with a "traced client" middleware for observability.
+//!
+//! Built on [`common::logging::create_timing_span`], so every call's span
+//! carries its true elapsed time, and on [`MetricsLayer`](crate::middleware::MetricsLayer)'s
+//! [`CallMetrics`] for the counters this layer accumulates per method.
+
+use crate::blockchain::{BlockchainDataProvider, BlockchainError};
+use crate::middleware::{impl_provider_via_middleware, CallMetrics, ProviderMiddleware};
+use crate::models::{SecurityAnalysis, SmartContract, Transaction};
+use async_trait::async_trait;
+use common::{
+    error::{Error, Result},
+    logging::create_timing_span,
+    types::{Address, Hash, TimeRange},
+};
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::Instant;
+use tracing::{debug, error, warn, Instrument};
+
+fn log_provider_error(endpoint_label: &str, method: &str, error: &Error) {
+    match error.downcast_ref::<BlockchainError>() {
+        Some(BlockchainError::RPCError(_)) | Some(BlockchainError::ConnectionError(_)) => {
+            error!(endpoint = endpoint_label, method, %error, "blockchain RPC call failed");
+        }
+        Some(_) => {
+            warn!(endpoint = endpoint_label, method, %error, "blockchain call returned an application error");
+        }
+        None => {
+            warn!(endpoint = endpoint_label, method, %error, "blockchain call failed");
+        }
+    }
+}
+
+/// Wraps a provider, opening a timing span and recording per-method
+/// `CallMetrics` for every RPC call, and logging `BlockchainError`s at
+/// `warn`/`error` labeled with the endpoint they came from
+///
+/// Gives operators visibility into which blockchain calls against a given
+/// endpoint are slow or failing in production, via [`TracedProvider::metrics`]
+/// or the [`TracedProvider::prometheus`] text exporter.
+pub struct TracedProvider<P> {
+    inner: P,
+    endpoint_label: String,
+    metrics: Mutex<HashMap<&'static str, CallMetrics>>,
+}
+
+impl<P: BlockchainDataProvider> TracedProvider<P> {
+    /// Wraps `inner`, labeling every span and log line with `endpoint_label`
+    /// (e.g. the RPC endpoint URL or node name)
+    pub fn new(inner: P, endpoint_label: impl Into<String>) -> Self {
+        Self { inner, endpoint_label: endpoint_label.into(), metrics: Mutex::new(HashMap::new()) }
+    }
+
+    /// Snapshots the metrics recorded so far, keyed by method name
+    pub fn metrics(&self) -> HashMap<String, CallMetrics> {
+        self.metrics.lock().unwrap().iter().map(|(name, metrics)| (name.to_string(), metrics.clone())).collect()
+    }
+
+    /// Renders the collected metrics as Prometheus exposition text: a
+    /// `blockchain_provider_calls_total`/`_errors_total`/`_duration_seconds_sum`
+    /// triplet per method, labeled by `endpoint` and `method`
+    pub fn prometheus(&self) -> String {
+        let mut out = String::new();
+        for (method, m) in self.metrics.lock().unwrap().iter() {
+            let labels = format!("endpoint=\"{}\",method=\"{}\"", self.endpoint_label, method);
+            let _ = writeln!(out, "blockchain_provider_calls_total{{{labels}}} {}", m.calls);
+            let _ = writeln!(out, "blockchain_provider_errors_total{{{labels}}} {}", m.errors);
+            let _ =
+                writeln!(out, "blockchain_provider_duration_seconds_sum{{{labels}}} {}", m.total_duration.as_secs_f64());
+        }
+        out
+    }
+
+    /// Opens a timing span tagged with the endpoint label and `method`, runs
+    /// `future` inside it after a debug event carrying `args`, records the
+    /// result in `metrics`, and logs any error
+    ///
+    /// `args` appears only on that debug event; it is not a field of the
+    /// span itself.
+    ///
+    /// `future` is driven via `Instrument::instrument` rather than entering
+    /// the span and holding the guard across the `.await`: on a
+    /// multi-threaded runtime, a held `Entered` guard stays active on the
+    /// worker thread while this task is suspended, misattributing events
+    /// from unrelated tasks polled on that thread to this span.
+    async fn traced<T, F>(&self, method: &'static str, args: String, future: F) -> Result<T>
+    where
+        F: Future<Output = Result<T>>,
+    {
+        let span = create_timing_span(&self.endpoint_label, method);
+        let start = Instant::now();
+        let result = async {
+            debug!(args = %args, method, endpoint = %self.endpoint_label, "dispatching blockchain call");
+            future.await
+        }
+        .instrument(span.span())
+        .await;
+        let elapsed = start.elapsed();
+
+        {
+            let mut metrics = self.metrics.lock().unwrap();
+            let entry = metrics.entry(method).or_default();
+            entry.calls += 1;
+            entry.total_duration += elapsed;
+            if result.is_err() {
+                entry.errors += 1;
+            }
+        }
+
+        if let Err(error) = &result {
+            log_provider_error(&self.endpoint_label, method, error);
+        }
+
+        result
+    }
+}
+
+#[async_trait]
+impl<P: BlockchainDataProvider> ProviderMiddleware for TracedProvider<P> {
+    type Inner = P;
+
+    fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    async fn get_transaction(&self, hash: &Hash) -> Result<Transaction> {
+        self.traced("get_transaction", hash.to_string(), self.inner.get_transaction(hash)).await
+    }
+
+    async fn get_contract(&self, address: &Address) -> Result<SmartContract> {
+        self.traced("get_contract", address.to_string(), self.inner.get_contract(address)).await
+    }
+
+    async fn get_transactions_in_range(&self, range: TimeRange) -> Result<Vec<Transaction>> {
+        self.traced(
+            "get_transactions_in_range",
+            format!("{}..{}", range.start, range.end),
+            self.inner.get_transactions_in_range(range),
+        )
+        .await
+    }
+
+    async fn get_address_transactions(&self, address: &Address) -> Result<Vec<Transaction>> {
+        self.traced("get_address_transactions", address.to_string(), self.inner.get_address_transactions(address)).await
+    }
+
+    async fn get_balance(&self, address: &Address) -> Result<u64> {
+        self.traced("get_balance", address.to_string(), self.inner.get_balance(address)).await
+    }
+
+    async fn get_nonce(&self, address: &Address) -> Result<u64> {
+        self.traced("get_nonce", address.to_string(), self.inner.get_nonce(address)).await
+    }
+
+    async fn analyze_contract(&self, address: &Address) -> Result<SecurityAnalysis> {
+        self.traced("analyze_contract", address.to_string(), self.inner.analyze_contract(address)).await
+    }
+
+    async fn get_code(&self, address: &Address) -> Result<Vec<u8>> {
+        self.traced("get_code", address.to_string(), self.inner.get_code(address)).await
+    }
+
+    async fn get_storage_at(&self, address: &Address, slot: &[u8; 32]) -> Result<[u8; 32]> {
+        self.traced("get_storage_at", format!("{address} @ {}", hex::encode(slot)), self.inner.get_storage_at(address, slot))
+            .await
+    }
+}
+impl_provider_via_middleware!(TracedProvider);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::types::RiskLevel;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+
+    struct FlakyProvider {
+        fail_n_times: Arc<AtomicU32>,
+    }
+
+    impl FlakyProvider {
+        fn failing(n: u32) -> (Self, Arc<AtomicU32>) {
+            let fail_n_times = Arc::new(AtomicU32::new(n));
+            (Self { fail_n_times: Arc::clone(&fail_n_times) }, fail_n_times)
+        }
+    }
+
+    #[async_trait]
+    impl BlockchainDataProvider for FlakyProvider {
+        async fn get_transaction(&self, hash: &Hash) -> Result<Transaction> {
+            Err(Error::NotFound(hash.to_string()))
+        }
+
+        async fn get_contract(&self, address: &Address) -> Result<SmartContract> {
+            Err(Error::Other(Box::new(BlockchainError::ContractNotFound(address.to_string()))))
+        }
+
+        async fn get_transactions_in_range(&self, _range: TimeRange) -> Result<Vec<Transaction>> {
+            Ok(Vec::new())
+        }
+
+        async fn get_address_transactions(&self, _address: &Address) -> Result<Vec<Transaction>> {
+            Ok(Vec::new())
+        }
+
+        async fn get_balance(&self, _address: &Address) -> Result<u64> {
+            let remaining = self.fail_n_times.load(Ordering::SeqCst);
+            if remaining > 0 {
+                self.fail_n_times.fetch_sub(1, Ordering::SeqCst);
+                return Err(Error::Other(Box::new(BlockchainError::RPCError("temporarily unavailable".to_string()))));
+            }
+            Ok(42)
+        }
+
+        async fn get_nonce(&self, _address: &Address) -> Result<u64> {
+            Ok(0)
+        }
+
+        async fn analyze_contract(&self, _address: &Address) -> Result<SecurityAnalysis> {
+            Ok(SecurityAnalysis { risk_level: RiskLevel::None, risk_score: 0, findings: Vec::new(), metadata: HashMap::new() })
+        }
+
+        async fn get_code(&self, _address: &Address) -> Result<Vec<u8>> {
+            Ok(Vec::new())
+        }
+
+        async fn get_storage_at(&self, _address: &Address, _slot: &[u8; 32]) -> Result<[u8; 32]> {
+            Ok([0u8; 32])
+        }
+    }
+
+    #[tokio::test]
+    async fn test_traced_provider_records_calls_and_errors_per_method() {
+        let (provider, _) = FlakyProvider::failing(1);
+        let traced = TracedProvider::new(provider, "test-node".to_string());
+        let address = Address("0xabc".to_string());
+
+        assert!(traced.get_balance(&address).await.is_err());
+        assert!(traced.get_balance(&address).await.is_ok());
+
+        let metrics = traced.metrics();
+        let balance_metrics = metrics.get("get_balance").unwrap();
+        assert_eq!(balance_metrics.calls, 2);
+        assert_eq!(balance_metrics.errors, 1);
+    }
+
+    #[tokio::test]
+    async fn test_traced_provider_tracks_multiple_methods_independently() {
+        let (provider, _) = FlakyProvider::failing(0);
+        let traced = TracedProvider::new(provider, "test-node".to_string());
+        let address = Address("0xabc".to_string());
+
+        traced.get_balance(&address).await.unwrap();
+        traced.get_nonce(&address).await.unwrap();
+        traced.get_nonce(&address).await.unwrap();
+
+        let metrics = traced.metrics();
+        assert_eq!(metrics.get("get_balance").unwrap().calls, 1);
+        assert_eq!(metrics.get("get_nonce").unwrap().calls, 2);
+    }
+
+    #[tokio::test]
+    async fn test_prometheus_exporter_includes_recorded_methods() {
+        let (provider, _) = FlakyProvider::failing(0);
+        let traced = TracedProvider::new(provider, "test-node".to_string());
+        traced.get_nonce(&Address("0xabc".to_string())).await.unwrap();
+
+        let text = traced.prometheus();
+        assert!(text.contains("blockchain_provider_calls_total{endpoint=\"test-node\",method=\"get_nonce\"} 1"));
+    }
+}
@@ -1,66 +1,176 @@
 // common/src/async_utils.rs
 use crate::error::{Error, Result};
+use rand::Rng;
 use std::time::Duration;
 use tokio::time::sleep;
 
-/// Retry an async operation with exponential backoff
-/// 
+/// Controls retry count, backoff bounds, and which errors are worth retrying
+///
 /// # Examples
-/// 
+///
 /// ```
-/// use common::async_utils::retry_with_backoff;
+/// use common::async_utils::RetryPolicy;
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::new(5, Duration::from_millis(100), Duration::from_secs(10))
+///     .with_retryable(|e| matches!(e, common::error::Error::Network(_)));
+/// assert_eq!(policy.max_retries, 5);
+/// ```
+#[derive(Clone)]
+pub struct RetryPolicy {
+    /// Maximum number of retries after the initial attempt
+    pub max_retries: u32,
+    /// Upper bound of the randomized delay before the first retry; the bound
+    /// doubles with each later retry, up to `max_delay`
+    pub initial_delay: Duration,
+    /// Upper bound on the computed delay, regardless of attempt count
+    pub max_delay: Duration,
+    /// Predicate deciding whether an error is worth retrying at all; when
+    /// `None`, every error is retried. A `false` result fails fast without
+    /// sleeping.
+    pub is_retryable: Option<fn(&Error) -> bool>,
+}
+
+impl RetryPolicy {
+    /// Creates a policy with no retryable-error predicate (retries everything)
+    pub fn new(max_retries: u32, initial_delay: Duration, max_delay: Duration) -> Self {
+        Self {
+            max_retries,
+            initial_delay,
+            max_delay,
+            is_retryable: None,
+        }
+    }
+
+    /// Attaches a predicate used to classify which errors should be retried
+    pub fn with_retryable(mut self, is_retryable: fn(&Error) -> bool) -> Self {
+        self.is_retryable = Some(is_retryable);
+        self
+    }
+}
+
+/// Computes a full-jitter delay for the given zero-indexed retry attempt:
+/// `random_uniform(0, min(max_delay, initial_delay * 2^attempt))`, with the
+/// exponent saturated so large attempt counts can't overflow the multiply.
+fn full_jitter_delay(policy: &RetryPolicy, attempt: u32) -> Duration {
+    let initial_ms = policy.initial_delay.as_millis().min(u128::from(u64::MAX)) as u64;
+    let shift = attempt.min(63);
+    let factor = 1u64.checked_shl(shift).unwrap_or(u64::MAX);
+    let exponential_ms = initial_ms.saturating_mul(factor);
+
+    let max_ms = policy.max_delay.as_millis().min(u128::from(u64::MAX)) as u64;
+    let capped_ms = exponential_ms.min(max_ms);
+
+    let jittered_ms = if capped_ms == 0 {
+        0
+    } else {
+        rand::thread_rng().gen_range(0..=capped_ms)
+    };
+
+    Duration::from_millis(jittered_ms)
+}
+
+/// Retry an async operation according to a `RetryPolicy`
+///
+/// Uses full-jitter exponential backoff (delay capped at `policy.max_delay`)
+/// and, when `policy.is_retryable` is set, fails fast without sleeping on
+/// errors the predicate rejects.
+///
+/// # Examples
+///
+/// ```
+/// use common::async_utils::{retry_with_policy, RetryPolicy};
 /// use common::error::Result;
-/// 
+/// use std::time::Duration;
+///
 /// async fn fallible_operation() -> Result<String> {
-///     // Simulate an operation that might fail
 ///     Ok("success".to_string())
 /// }
-/// 
+///
 /// # tokio_test::block_on(async {
-/// let result = retry_with_backoff(
-///     || async { fallible_operation().await },
-///     3,                    // max retries
-///     Duration::from_secs(1) // initial delay
-/// ).await;
+/// let policy = RetryPolicy::new(3, Duration::from_millis(10), Duration::from_secs(1));
+/// let result = retry_with_policy(|| async { fallible_operation().await }, policy).await;
 /// assert!(result.is_ok());
 /// # })
 /// ```
-pub async fn retry_with_backoff<F, Fut, T>(f: F, max_retries: u32, initial_delay: Duration) -> Result<T>
+pub async fn retry_with_policy<F, Fut, T>(f: F, policy: RetryPolicy) -> Result<T>
 where
     F: Fn() -> Fut,
     Fut: std::future::Future<Output = Result<T>>,
 {
-    let mut current_try = 0;
-    let mut current_delay = initial_delay;
+    let mut attempt: u32 = 0;
 
     loop {
         match f().await {
             Ok(value) => return Ok(value),
             Err(e) => {
-                current_try += 1;
-                if current_try > max_retries {
+                if let Some(is_retryable) = policy.is_retryable {
+                    if !is_retryable(&e) {
+                        return Err(e);
+                    }
+                }
+
+                attempt += 1;
+                if attempt > policy.max_retries {
                     return Err(e);
                 }
-                sleep(current_delay).await;
-                current_delay *= 2; // Exponential backoff
+
+                sleep(full_jitter_delay(&policy, attempt - 1)).await;
             }
         }
     }
 }
 
+/// Retry an async operation with exponential backoff
+///
+/// A thin wrapper over [`retry_with_policy`] that preserves this function's
+/// original behavior: every error is retried, and the delay grows
+/// exponentially (now with full jitter and a generous cap instead of
+/// doubling unboundedly).
+///
+/// # Examples
+///
+/// ```
+/// use common::async_utils::retry_with_backoff;
+/// use common::error::Result;
+/// use std::time::Duration;
+///
+/// async fn fallible_operation() -> Result<String> {
+///     // Simulate an operation that might fail
+///     Ok("success".to_string())
+/// }
+///
+/// # tokio_test::block_on(async {
+/// let result = retry_with_backoff(
+///     || async { fallible_operation().await },
+///     3,                    // max retries
+///     Duration::from_secs(1) // initial delay
+/// ).await;
+/// assert!(result.is_ok());
+/// # })
+/// ```
+pub async fn retry_with_backoff<F, Fut, T>(f: F, max_retries: u32, initial_delay: Duration) -> Result<T>
+where
+    F: Fn() -> Fut,
+    Fut: std::future::Future<Output = Result<T>>,
+{
+    let policy = RetryPolicy::new(max_retries, initial_delay, Duration::from_secs(3600));
+    retry_with_policy(f, policy).await
+}
+
 /// Run multiple async operations with a timeout
-/// 
+///
 /// # Examples
-/// 
+///
 /// ```
 /// use common::async_utils::with_timeout;
 /// use std::time::Duration;
-/// 
+///
 /// async fn long_operation() -> String {
 ///     tokio::time::sleep(Duration::from_millis(50)).await;
 ///     "completed".to_string()
 /// }
-/// 
+///
 /// # tokio_test::block_on(async {
 /// // This should complete successfully
 /// let result = with_timeout(
@@ -68,7 +178,7 @@ where
 ///     long_operation()
 /// ).await;
 /// assert!(result.is_ok());
-/// 
+///
 /// // This should timeout
 /// let result = with_timeout(
 ///     Duration::from_millis(10),
@@ -119,12 +229,58 @@ mod tests {
         assert_eq!(counter.load(Ordering::SeqCst), 3);
     }
 
+    #[tokio::test]
+    async fn test_retry_with_policy_exhausts_retries() {
+        let policy = RetryPolicy::new(2, Duration::from_millis(1), Duration::from_millis(5));
+
+        let result: Result<()> = retry_with_policy(
+            || async { Err(Error::Internal("always fails".to_string())) },
+            policy,
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_retry_with_policy_fails_fast_on_non_retryable() {
+        let counter = Arc::new(AtomicU32::new(0));
+        let counter_clone = counter.clone();
+
+        let policy = RetryPolicy::new(5, Duration::from_millis(1), Duration::from_millis(5))
+            .with_retryable(|_| false);
+
+        let operation = move || {
+            let counter = counter_clone.clone();
+            async move {
+                counter.fetch_add(1, Ordering::SeqCst);
+                Err::<(), Error>(Error::Network("unreachable".to_string()))
+            }
+        };
+
+        let result = retry_with_policy(operation, policy).await;
+
+        assert!(result.is_err());
+        // Only the initial attempt ran — no retries since is_retryable always rejects.
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_full_jitter_delay_saturates_and_caps() {
+        let policy = RetryPolicy::new(u32::MAX, Duration::from_secs(1), Duration::from_secs(10));
+
+        // A huge attempt count must not overflow/panic, and the delay must
+        // never exceed max_delay.
+        let delay = full_jitter_delay(&policy, u32::MAX);
+        assert!(delay <= Duration::from_secs(10));
+    }
+
     #[tokio::test]
     async fn test_timeout() {
         // Test successful completion
         let result = with_timeout(
             Duration::from_millis(100),
-            async { 
+            async {
                 sleep(Duration::from_millis(50)).await;
                 42
             }
@@ -135,11 +291,11 @@ mod tests {
         // Test timeout
         let result = with_timeout(
             Duration::from_millis(50),
-            async { 
+            async {
                 sleep(Duration::from_millis(100)).await;
                 42
             }
         ).await;
         assert!(result.is_err());
     }
-}
\ No newline at end of file
+}
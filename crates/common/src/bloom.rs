@@ -0,0 +1,150 @@
+// common/src/bloom.rs
+use crate::types::Address;
+use sha3::{Digest, Keccak256};
+
+/// Number of bits in the Ethereum-style bloom filter (2048 = 256 bytes)
+const BLOOM_BITS: usize = 2048;
+const BLOOM_BYTES: usize = BLOOM_BITS / 8;
+
+/// A 2048-bit Ethereum-style bloom filter
+///
+/// Used to cheaply test whether a block or batch of transactions *might*
+/// involve a given address or hash before doing a full analysis pass.
+/// Membership tests never produce false negatives, but can produce false
+/// positives.
+///
+/// # Examples
+///
+/// ```
+/// use common::bloom::Bloom;
+///
+/// let mut bloom = Bloom::new();
+/// bloom.insert(b"hello");
+///
+/// assert!(bloom.contains(b"hello"));
+/// assert!(!bloom.contains(b"world"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bloom([u8; BLOOM_BYTES]);
+
+impl Bloom {
+    /// Creates an empty bloom filter
+    pub fn new() -> Self {
+        Self([0u8; BLOOM_BYTES])
+    }
+
+    /// Returns the three bit indices (each in `[0, 2047]`) that `item`'s
+    /// keccak256 digest maps to
+    fn bit_indices(item: &[u8]) -> [usize; 3] {
+        let mut hasher = Keccak256::new();
+        hasher.update(item);
+        let digest = hasher.finalize();
+
+        let mut indices = [0usize; 3];
+        for i in 0..3 {
+            let word = u16::from_be_bytes([digest[2 * i], digest[2 * i + 1]]);
+            indices[i] = (word & 0x7FF) as usize;
+        }
+        indices
+    }
+
+    /// Sets the three bits derived from `item`'s keccak256 digest
+    pub fn insert(&mut self, item: &[u8]) {
+        for bit in Self::bit_indices(item) {
+            self.0[bit / 8] |= 1 << (bit % 8);
+        }
+    }
+
+    /// Tests whether `item` might be present (no false negatives)
+    pub fn contains(&self, item: &[u8]) -> bool {
+        Self::bit_indices(item)
+            .iter()
+            .all(|&bit| self.0[bit / 8] & (1 << (bit % 8)) != 0)
+    }
+
+    /// Merges another bloom filter into this one (bitwise OR)
+    pub fn union(&mut self, other: &Bloom) {
+        for (a, b) in self.0.iter_mut().zip(other.0.iter()) {
+            *a |= b;
+        }
+    }
+}
+
+impl Default for Bloom {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Builds a bloom filter with every address in `addresses` inserted
+///
+/// Only addresses are covered: this takes no transactions, and no
+/// transaction hash is inserted.
+///
+/// # Examples
+///
+/// ```
+/// use common::bloom::bloom_from_addresses;
+/// use common::types::Address;
+///
+/// let addresses = vec![Address("0xabc".to_string())];
+/// let bloom = bloom_from_addresses(&addresses);
+/// assert!(bloom.contains(b"0xabc"));
+/// ```
+pub fn bloom_from_addresses(addresses: &[Address]) -> Bloom {
+    let mut bloom = Bloom::new();
+    for address in addresses {
+        bloom.insert(address.0.as_bytes());
+    }
+    bloom
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_and_contains() {
+        let mut bloom = Bloom::new();
+        bloom.insert(b"0xabc");
+        assert!(bloom.contains(b"0xabc"));
+        assert!(!bloom.contains(b"0xdef"));
+    }
+
+    #[test]
+    fn test_union() {
+        let mut a = Bloom::new();
+        a.insert(b"0xabc");
+
+        let mut b = Bloom::new();
+        b.insert(b"0xdef");
+
+        a.union(&b);
+        assert!(a.contains(b"0xabc"));
+        assert!(a.contains(b"0xdef"));
+    }
+
+    #[test]
+    fn test_bloom_from_addresses() {
+        let addresses = vec![
+            Address("0xabc".to_string()),
+            Address("0xdef".to_string()),
+        ];
+        let bloom = bloom_from_addresses(&addresses);
+        assert!(bloom.contains(b"0xabc"));
+        assert!(bloom.contains(b"0xdef"));
+        assert!(!bloom.contains(b"0x123"));
+    }
+
+    #[test]
+    fn test_no_false_negatives_across_many_items() {
+        let mut bloom = Bloom::new();
+        let items: Vec<String> = (0..50).map(|i| format!("0xitem{}", i)).collect();
+        for item in &items {
+            bloom.insert(item.as_bytes());
+        }
+        for item in &items {
+            assert!(bloom.contains(item.as_bytes()));
+        }
+    }
+}
@@ -0,0 +1,155 @@
+// common/src/crypto.rs
+//! secp256k1 ECDSA signature recovery and verification
+//!
+//! Shared by any crate that needs to check whether a transaction was
+//! actually authorized by its claimed sender, rather than trusting a
+//! self-reported `from` field — e.g. `transaction-analyzer`'s
+//! `SignatureAnalyzer`.
+
+use crate::error::{Error, Result};
+use crate::types::{Address, Hash};
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use sha3::{Digest, Keccak256};
+
+/// secp256k1 half curve order (`n / 2`, big-endian) — the EIP-2 bound above
+/// which an `s` value is considered malleable
+pub const SECP256K1_HALF_ORDER: [u8; 32] = [
+    0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0x5D, 0x57, 0x6E, 0x73, 0x57, 0xA4, 0x50, 0x1D, 0xDF, 0xE9, 0x2F, 0x46, 0x68, 0x1B, 0x20, 0xA0,
+];
+
+fn decode_msg_hash(msg_hash: &Hash) -> Result<[u8; 32]> {
+    let bytes = crate::utils::hex_to_bytes(&msg_hash.0).map_err(Error::Validation)?;
+    bytes
+        .try_into()
+        .map_err(|_| Error::Validation(format!("signing hash must be 32 bytes: {}", msg_hash)))
+}
+
+/// Recovers the secp256k1 signer address for a signing hash and signature
+///
+/// Recovers the public key, computes its keccak256, and returns the low 20
+/// bytes as an `Address` — the standard Ethereum address derivation.
+///
+/// # Examples
+///
+/// ```
+/// use common::crypto::recover_signer;
+/// use common::types::Hash;
+///
+/// // An all-zero signature is not a valid scalar pair, so recovery must
+/// // fail closed rather than silently accepting a forged sender.
+/// let hash = Hash::from_hex(
+///     "0x8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925"
+/// ).unwrap();
+/// assert!(recover_signer(&hash, [0u8; 32], [0u8; 32], 0).is_err());
+/// ```
+pub fn recover_signer(msg_hash: &Hash, r: [u8; 32], s: [u8; 32], v: u8) -> Result<Address> {
+    let signing_hash = decode_msg_hash(msg_hash)?;
+
+    let signature =
+        Signature::from_scalars(r, s).map_err(|e| Error::Validation(format!("invalid signature scalars: {}", e)))?;
+    let recovery_id =
+        RecoveryId::from_byte(v).ok_or_else(|| Error::Validation(format!("invalid recovery id: {}", v)))?;
+
+    let verifying_key = VerifyingKey::recover_from_prehash(&signing_hash, &signature, recovery_id)
+        .map_err(|e| Error::Validation(format!("signature recovery failed: {}", e)))?;
+
+    let encoded_point = verifying_key.to_encoded_point(false);
+    // Drop the leading 0x04 (uncompressed point) tag before hashing.
+    let public_key_bytes = &encoded_point.as_bytes()[1..];
+
+    let mut hasher = Keccak256::new();
+    hasher.update(public_key_bytes);
+    let digest = hasher.finalize();
+
+    Address::from_hex(&format!("0x{}", hex::encode(&digest[12..])))
+}
+
+/// Recovers the signer for `msg_hash`/`r`/`s`/`v` and checks it matches
+/// `expected_signer`
+///
+/// # Examples
+///
+/// ```
+/// use common::crypto::verify_signature;
+/// use common::types::{Address, Hash};
+///
+/// let hash = Hash::from_hex(
+///     "0x8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925"
+/// ).unwrap();
+/// let expected = Address("0xabc".to_string());
+///
+/// // All-zero r/s can't even be recovered, so it's never "verified".
+/// assert!(verify_signature(&hash, [0u8; 32], [0u8; 32], 0, &expected).is_err());
+/// ```
+pub fn verify_signature(msg_hash: &Hash, r: [u8; 32], s: [u8; 32], v: u8, expected_signer: &Address) -> Result<bool> {
+    let recovered = recover_signer(msg_hash, r, s, v)?;
+    Ok(recovered.0.eq_ignore_ascii_case(&expected_signer.0))
+}
+
+/// Checks whether `s` exceeds the secp256k1 half curve order, the low-s
+/// malleability bound from EIP-2
+///
+/// # Examples
+///
+/// ```
+/// use common::crypto::is_malleable;
+///
+/// assert!(!is_malleable(&[0u8; 32]));
+/// assert!(is_malleable(&[0xFFu8; 32]));
+/// ```
+pub fn is_malleable(s: &[u8; 32]) -> bool {
+    s.as_slice() > SECP256K1_HALF_ORDER.as_slice()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k256::ecdsa::{RecoveryId, SigningKey};
+
+    fn sample_hash() -> Hash {
+        Hash::from_hex("0x8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925").unwrap()
+    }
+
+    fn sign(signing_key: &SigningKey, msg_hash: &[u8; 32]) -> (Signature, RecoveryId) {
+        signing_key.sign_prehash_recoverable(msg_hash).unwrap()
+    }
+
+    #[test]
+    fn test_recover_signer_round_trips_with_signing_key() {
+        let signing_key = SigningKey::from_bytes(&[0x11u8; 32].into()).unwrap();
+        let verifying_key = VerifyingKey::from(&signing_key);
+        let encoded_point = verifying_key.to_encoded_point(false);
+        let digest = {
+            let mut hasher = Keccak256::new();
+            hasher.update(&encoded_point.as_bytes()[1..]);
+            hasher.finalize()
+        };
+        let expected = Address::from_hex(&format!("0x{}", hex::encode(&digest[12..]))).unwrap();
+
+        let msg_hash = sample_hash();
+        let msg_hash_bytes = decode_msg_hash(&msg_hash).unwrap();
+        let (signature, recovery_id) = sign(&signing_key, &msg_hash_bytes);
+        let signature_bytes = signature.to_bytes();
+        let r: [u8; 32] = signature_bytes[..32].try_into().unwrap();
+        let s: [u8; 32] = signature_bytes[32..].try_into().unwrap();
+
+        let recovered = recover_signer(&msg_hash, r, s, recovery_id.to_byte()).unwrap();
+        assert_eq!(recovered, expected);
+
+        assert!(verify_signature(&msg_hash, r, s, recovery_id.to_byte(), &expected).unwrap());
+    }
+
+    #[test]
+    fn test_recover_signer_rejects_invalid_scalars() {
+        let msg_hash = sample_hash();
+        assert!(recover_signer(&msg_hash, [0u8; 32], [0u8; 32], 0).is_err());
+    }
+
+    #[test]
+    fn test_malleability_bound() {
+        assert!(!is_malleable(&[0u8; 32]));
+        assert!(is_malleable(&[0xFFu8; 32]));
+    }
+}
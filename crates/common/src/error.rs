@@ -19,4 +19,22 @@ pub enum Error {
 
     #[error("Internal error: {0}")]
     Internal(String),
-}
\ No newline at end of file
+
+    /// Wraps an arbitrary error from a downstream crate (e.g. `BlockchainError`)
+    /// so it can flow through the common `Result` type without flattening it
+    /// to a string; use `downcast_ref` to recover the concrete type.
+    #[error(transparent)]
+    Other(#[from] Box<dyn std::error::Error + Send + Sync>),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    /// Attempts to downcast a wrapped `Other` error back to its concrete type
+    pub fn downcast_ref<T: std::error::Error + 'static>(&self) -> Option<&T> {
+        match self {
+            Error::Other(err) => err.downcast_ref::<T>(),
+            _ => None,
+        }
+    }
+}
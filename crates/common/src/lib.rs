@@ -1,11 +1,15 @@
+pub mod async_utils;
+pub mod bloom;
+pub mod crypto;
 pub mod error;
 pub mod logging;
 pub mod types;
 pub mod utils;
-pub mod async_utils;
 
 // Re-export common types
+pub use async_utils::{retry_with_backoff, retry_with_policy, with_timeout, RetryPolicy};
+pub use bloom::Bloom;
+pub use crypto::{is_malleable, recover_signer, verify_signature};
 pub use error::{Error, Result};
 pub use types::{Address, Hash, RiskLevel, TimeRange};
-pub use utils::{current_timestamp, hex_to_bytes, bytes_to_hex};
-pub use async_utils::{retry_with_backoff, with_timeout};
\ No newline at end of file
+pub use utils::{bytes_to_hex, current_timestamp, hex_to_bytes};
\ No newline at end of file
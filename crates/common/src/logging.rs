@@ -0,0 +1,441 @@
+// common/src/logging.rs
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::Instant;
+use regex::Regex;
+use tokio::sync::OnceCell;
+use tracing::{Level, Subscriber};
+use tracing_subscriber::{
+    fmt::{format, FmtContext, FormatEvent, FormatFields},
+    fmt::format::FmtSpan,
+    registry::LookupSpan,
+    EnvFilter,
+};
+
+static ASYNC_LOGGER: OnceCell<()> = OnceCell::const_new();
+
+/// Initialize the global logger with sensible defaults asynchronously
+///
+/// This sets up logging with:
+/// - INFO level by default
+/// - Console output
+/// - Thread IDs
+/// - File and line numbers
+/// - Full span events
+///
+/// # Examples
+///
+/// ```
+/// use common::logging::init_logger;
+/// use tracing::info;
+///
+/// # tokio_test::block_on(async {
+/// // Initialize the default logger
+/// init_logger().await;
+///
+/// // Log some information
+/// info!("Application started");
+/// # })
+/// ```
+pub async fn init_logger() {
+    init_logger_with_level(Level::INFO).await;
+}
+
+/// Initialize the global logger with a specific level asynchronously
+///
+/// # Examples
+///
+/// ```
+/// use common::logging::init_logger_with_level;
+/// use tracing::{debug, Level};
+///
+/// # tokio_test::block_on(async {
+/// // Initialize logger with debug level
+/// init_logger_with_level(Level::DEBUG).await;
+///
+/// // Now debug logs will be visible
+/// debug!("Detailed debug information");
+/// # })
+/// ```
+pub async fn init_logger_with_level(level: Level) {
+    ASYNC_LOGGER.get_or_init(|| async {
+        let env_filter = EnvFilter::try_from_default_env()
+            .unwrap_or_else(|_| EnvFilter::new(format!("common={}", level.as_str())));
+
+        tracing_subscriber::fmt()
+            .with_env_filter(env_filter)
+            .with_span_events(FmtSpan::FULL)
+            .with_target(true)
+            .with_thread_ids(true)
+            .with_line_number(true)
+            .with_file(true)
+            .init();
+    }).await;
+}
+
+/// Initialize a JSON logger for production environments asynchronously
+///
+/// This sets up structured logging in JSON format, which is useful for:
+/// - Log aggregation systems
+/// - Cloud logging platforms
+/// - Production environments where machine-readable logs are needed
+///
+/// # Examples
+///
+/// ```
+/// use common::logging::init_json_logger;
+/// use tracing::{info, warn};
+///
+/// # tokio_test::block_on(async {
+/// // Initialize the JSON logger
+/// init_json_logger().await;
+///
+/// // Log events will now be output in JSON format
+/// info!("System status nominal");
+/// warn!(error_code = 123, "Resource usage high");
+/// # })
+/// ```
+pub async fn init_json_logger() {
+    ASYNC_LOGGER.get_or_init(|| async {
+        let env_filter = EnvFilter::try_from_default_env()
+            .unwrap_or_else(|_| EnvFilter::new("info"));
+
+        tracing_subscriber::fmt()
+            .json()
+            .with_env_filter(env_filter)
+            .with_span_events(FmtSpan::FULL)
+            .with_target(true)
+            .with_thread_ids(true)
+            .with_line_number(true)
+            .with_file(true)
+            .init();
+    }).await;
+}
+
+/// How a [`RedactConfig`] pattern match is written into the log output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RedactMode {
+    /// Replace every match with a stable, per-value pseudonym such as
+    /// `<addr_1>`, so the same real value always maps to the same token
+    /// across the run — hides the value while preserving correlation
+    /// between log lines
+    Pseudonym,
+    /// Replace every match with its pattern's bare label (e.g. `<addr>`),
+    /// discarding any correlation between occurrences of the same value
+    Full,
+}
+
+/// Configures [`init_logger_with_redaction`]: which [`RedactMode`] to mask
+/// matches with, and which regexes count as sensitive
+///
+/// Ships with patterns for 20-byte hex addresses and 32-byte hex hashes;
+/// use [`RedactConfig::with_pattern`] to cover anything else a deployment
+/// considers sensitive (e.g. a chain-specific balance or amount format).
+#[derive(Clone)]
+pub struct RedactConfig {
+    mode: RedactMode,
+    patterns: Vec<(String, Regex)>,
+}
+
+impl Default for RedactConfig {
+    fn default() -> Self {
+        Self {
+            mode: RedactMode::Pseudonym,
+            // `hash` (64 hex chars) must come before `addr` (40 hex chars):
+            // the regex crate has no lookaround, so if `addr` ran first it
+            // would match the leading 40 hex chars of a 64-char hash,
+            // rewrite them to a pseudonym, and leave the trailing 24 hex
+            // chars in cleartext with nothing left for `hash` to match.
+            patterns: vec![
+                ("hash".to_string(), Regex::new(r"0x[0-9a-fA-F]{64}").expect("valid hash regex")),
+                ("addr".to_string(), Regex::new(r"0x[0-9a-fA-F]{40}").expect("valid address regex")),
+            ],
+        }
+    }
+}
+
+impl RedactConfig {
+    /// Starts from the default address/hash patterns with the given mode
+    pub fn new(mode: RedactMode) -> Self {
+        Self { mode, ..Self::default() }
+    }
+
+    /// Registers an additional pattern under `label`; matches are masked as
+    /// `<label>` (full mode) or `<label_N>` (pseudonym mode)
+    pub fn with_pattern(mut self, label: impl Into<String>, pattern: Regex) -> Self {
+        self.patterns.push((label.into(), pattern));
+        self
+    }
+}
+
+/// Per-run state for [`RedactMode::Pseudonym`]: the stable value-to-token
+/// map, and the next token number to hand out per pattern label
+#[derive(Default)]
+struct RedactState {
+    pseudonyms: HashMap<String, String>,
+    counters: HashMap<String, usize>,
+}
+
+fn next_pseudonym(state: &mut RedactState, label: &str, value: &str) -> String {
+    if let Some(existing) = state.pseudonyms.get(value) {
+        return existing.clone();
+    }
+    let counter = state.counters.entry(label.to_string()).or_insert(0);
+    *counter += 1;
+    let token = format!("<{}_{}>", label, counter);
+    state.pseudonyms.insert(value.to_string(), token.clone());
+    token
+}
+
+/// A [`FormatEvent`] that formats events normally and then runs the result
+/// through every pattern in a [`RedactConfig`] before writing it out
+struct RedactingFormatter {
+    config: RedactConfig,
+    state: Mutex<RedactState>,
+}
+
+impl RedactingFormatter {
+    fn redact(&self, input: &str) -> String {
+        let mut output = input.to_string();
+        for (label, pattern) in &self.config.patterns {
+            let mut state = self.state.lock().expect("redaction state mutex poisoned");
+            output = pattern
+                .replace_all(&output, |caps: &regex::Captures<'_>| match self.config.mode {
+                    RedactMode::Full => format!("<{}>", label),
+                    RedactMode::Pseudonym => next_pseudonym(&mut state, label, &caps[0]),
+                })
+                .into_owned();
+        }
+        output
+    }
+}
+
+impl<S, N> FormatEvent<S, N> for RedactingFormatter
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: format::Writer<'_>,
+        event: &tracing::Event<'_>,
+    ) -> fmt::Result {
+        let metadata = event.metadata();
+        write!(writer, "{:>5} ", metadata.level())?;
+
+        if let Some(scope) = ctx.event_scope() {
+            for span in scope.from_root() {
+                write!(writer, "{}:", span.name())?;
+            }
+        }
+        write!(writer, "{}: ", metadata.target())?;
+
+        let mut fields = String::new();
+        ctx.format_fields(format::Writer::new(&mut fields), event)?;
+        write!(writer, "{}", self.redact(&fields))?;
+
+        writeln!(writer)
+    }
+}
+
+/// Initialize the global logger with an opt-in redaction layer so
+/// addresses, transaction/block hashes, and other matched identifiers come
+/// out as stable pseudonyms (or fully masked) rather than their real values
+///
+/// This lets users share logs in bug reports without leaking on-chain
+/// identities, while pseudonym mode still lets a reader correlate repeated
+/// occurrences of the same (now-hidden) value across lines.
+///
+/// # Examples
+///
+/// ```
+/// use common::logging::{init_logger_with_redaction, RedactConfig, RedactMode};
+/// use tracing::{info, Level};
+///
+/// # tokio_test::block_on(async {
+/// // Share the same value across log lines, correlated but never shown
+/// init_logger_with_redaction(Level::INFO, RedactConfig::new(RedactMode::Pseudonym)).await;
+///
+/// info!(address = "0x0000000000000000000000000000000000000001", "Contract deployed");
+/// # })
+/// ```
+pub async fn init_logger_with_redaction(level: Level, config: RedactConfig) {
+    ASYNC_LOGGER
+        .get_or_init(|| async move {
+            let env_filter = EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| EnvFilter::new(format!("common={}", level.as_str())));
+
+            let formatter = RedactingFormatter { config, state: Mutex::new(RedactState::default()) };
+
+            tracing_subscriber::fmt()
+                .with_env_filter(env_filter)
+                .event_format(formatter)
+                .with_span_events(FmtSpan::FULL)
+                .with_target(true)
+                .with_thread_ids(true)
+                .with_line_number(true)
+                .with_file(true)
+                .init();
+        })
+        .await;
+}
+
+/// Guard returned by [`create_timing_span`]
+///
+/// Holds the span open and, on drop, records the actual elapsed wall-clock
+/// time on the span's `elapsed_ms` field — not just the timestamp it
+/// started at — so the duration is visible in logs without a reader having
+/// to diff two timestamps themselves.
+pub struct TimingSpan {
+    span: tracing::Span,
+    start: Instant,
+}
+
+impl TimingSpan {
+    /// Enters the span for as long as the returned guard is held
+    ///
+    /// Only safe to hold across synchronous code: entering a span and
+    /// holding the guard across an `.await` point leaves it active on
+    /// whichever worker thread polls this task, which can misattribute
+    /// events from unrelated tasks. Use [`TimingSpan::span`] with
+    /// `tracing::Instrument::instrument` to carry a span across an
+    /// `.await` instead.
+    pub fn enter(&self) -> tracing::span::Entered<'_> {
+        self.span.enter()
+    }
+
+    /// Clones the underlying span, for attaching to a future via
+    /// `tracing::Instrument::instrument` rather than entering it directly
+    pub fn span(&self) -> tracing::Span {
+        self.span.clone()
+    }
+}
+
+impl Drop for TimingSpan {
+    fn drop(&mut self) {
+        self.span.record("elapsed_ms", self.start.elapsed().as_millis() as u64);
+    }
+}
+
+/// Create an async timing span for measuring operation duration
+///
+/// # Examples
+///
+/// ```
+/// use common::logging::create_timing_span;
+/// use tracing::{info, Instrument};
+/// use std::time::Duration;
+///
+/// # tokio_test::block_on(async {
+/// // Create a span for timing a database operation
+/// let span = create_timing_span("database_operation", "query_users");
+///
+/// // Carry the span across the `.await` with `Instrument` rather than an
+/// // `enter()` guard
+/// async {
+///     tokio::time::sleep(Duration::from_millis(100)).await;
+///     info!("Querying users table");
+/// }
+/// .instrument(span.span())
+/// .await;
+///
+/// // Span closes and records its real elapsed_ms when `span` is dropped
+/// # })
+/// ```
+pub fn create_timing_span(category: &str, operation: &str) -> TimingSpan {
+    let span = tracing::info_span!(
+        "timing",
+        category = category,
+        operation = operation,
+        elapsed_ms = tracing::field::Empty
+    );
+    TimingSpan { span, start: Instant::now() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing::info;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_logger_initialization() {
+        // Initialize logger with debug level
+        init_logger_with_level(Level::DEBUG).await;
+
+        // Test logging
+        info!("Test log message");
+    }
+
+    #[tokio::test]
+    async fn test_json_logger() {
+        // Initialize JSON logger
+        init_json_logger().await;
+
+        // Test structured logging
+        info!(
+            target: "test",
+            event = "test_event",
+            value = 42,
+            "Test JSON logging"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_timing_span() {
+        init_logger().await;
+        let span = create_timing_span("test", "operation");
+        let _entered = span.enter();
+
+        // Simulate some async work
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        info!("Operation in progress");
+    }
+
+    fn formatter(mode: RedactMode) -> RedactingFormatter {
+        RedactingFormatter { config: RedactConfig::new(mode), state: Mutex::new(RedactState::default()) }
+    }
+
+    #[test]
+    fn test_redact_pseudonymizes_addresses_and_hashes_distinctly() {
+        let f = formatter(RedactMode::Pseudonym);
+        let input = "from 0x0000000000000000000000000000000000000001 to 0x0000000000000000000000000000000000000002 in 0x2222222222222222222222222222222222222222222222222222222222222222";
+        let redacted = f.redact(input);
+        assert_eq!(redacted, "from <addr_1> to <addr_2> in <hash_1>");
+    }
+
+    #[test]
+    fn test_redact_pseudonym_is_stable_for_repeated_values() {
+        let f = formatter(RedactMode::Pseudonym);
+        let input = "0x0000000000000000000000000000000000000001 ... 0x0000000000000000000000000000000000000001";
+        let redacted = f.redact(input);
+        assert_eq!(redacted, "<addr_1> ... <addr_1>");
+    }
+
+    #[test]
+    fn test_redact_pseudonymizes_a_full_64_char_hash_with_no_leftover_hex() {
+        let f = formatter(RedactMode::Pseudonym);
+        let input = "tx 0x1111111111111111111111111111111111111111111111111111111111111111 confirmed";
+        let redacted = f.redact(input);
+        assert_eq!(redacted, "tx <hash_1> confirmed");
+    }
+
+    #[test]
+    fn test_redact_full_mode_discards_correlation() {
+        let f = formatter(RedactMode::Full);
+        let input = "0x0000000000000000000000000000000000000001 and 0x0000000000000000000000000000000000000002";
+        let redacted = f.redact(input);
+        assert_eq!(redacted, "<addr> and <addr>");
+    }
+
+    #[test]
+    fn test_redact_custom_pattern_is_applied() {
+        let config = RedactConfig::new(RedactMode::Pseudonym)
+            .with_pattern("balance", Regex::new(r"\bbalance=\d+\b").unwrap());
+        let f = RedactingFormatter { config, state: Mutex::new(RedactState::default()) };
+        let redacted = f.redact("transfer balance=1000 complete");
+        assert_eq!(redacted, "transfer <balance_1> complete");
+    }
+}
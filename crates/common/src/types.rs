@@ -1,17 +1,69 @@
 // common/src/types.rs
+use crate::error::{Error, Result};
 use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
 use std::fmt;
 
+/// Decodes a `0x`-prefixed hex string, requiring it to be exactly
+/// `expected_bytes` long once decoded. `label` is used to make the
+/// validation error identify which type rejected the input.
+fn decode_fixed_hex(s: &str, expected_bytes: usize, label: &str) -> Result<Vec<u8>> {
+    let stripped = s
+        .strip_prefix("0x")
+        .ok_or_else(|| Error::Validation(format!("{} must start with 0x: {}", label, s)))?;
+    if stripped.len() != expected_bytes * 2 {
+        return Err(Error::Validation(format!(
+            "{} must be {} bytes ({} hex chars), got {}",
+            label,
+            expected_bytes,
+            expected_bytes * 2,
+            stripped.len()
+        )));
+    }
+    crate::utils::hex_to_bytes(stripped)
+        .map_err(|e| Error::Validation(format!("{} is not valid hex: {}", label, e)))
+}
+
+/// Computes the EIP-55 checksum casing for a 40-character lowercase hex
+/// address (no `0x` prefix): each hex letter is uppercased iff the
+/// corresponding nibble of `keccak256(lowercase_hex)` is >= 8.
+fn eip55_checksum(lowercase_hex: &str) -> String {
+    let mut hasher = Keccak256::new();
+    hasher.update(lowercase_hex.as_bytes());
+    let digest = hasher.finalize();
+
+    lowercase_hex
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if c.is_ascii_alphabetic() {
+                let nibble = if i % 2 == 0 {
+                    digest[i / 2] >> 4
+                } else {
+                    digest[i / 2] & 0x0f
+                };
+                if nibble >= 8 {
+                    c.to_ascii_uppercase()
+                } else {
+                    c
+                }
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
 /// Represents a blockchain address
-/// 
+///
 /// # Examples
-/// 
+///
 /// ```
 /// use common::types::Address;
-/// 
+///
 /// let addr = Address("0x742d35Cc6634C0532925a3b844Bc454e4438f44e".to_string());
 /// assert_eq!(addr.to_string(), "0x742d35Cc6634C0532925a3b844Bc454e4438f44e");
-/// 
+///
 /// // Addresses can be cloned and compared
 /// let addr2 = addr.clone();
 /// assert_eq!(addr, addr2);
@@ -19,6 +71,71 @@ use std::fmt;
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Address(pub String);
 
+impl Address {
+    /// Parses and validates a hex-encoded address
+    ///
+    /// Requires a `0x` prefix and exactly 20 bytes (40 hex chars). The
+    /// stored value is normalized to lowercase; use [`Address::to_checksummed`]
+    /// to render an EIP-55 checksummed form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common::types::Address;
+    ///
+    /// let addr = Address::from_hex("0x742d35Cc6634C0532925a3b844Bc454e4438f44e").unwrap();
+    /// assert_eq!(addr.to_string(), "0x742d35cc6634c0532925a3b844bc454e4438f44e");
+    ///
+    /// assert!(Address::from_hex("742d35Cc6634C0532925a3b844Bc454e4438f44e").is_err());
+    /// assert!(Address::from_hex("0x1234").is_err());
+    /// ```
+    pub fn from_hex(s: &str) -> Result<Self> {
+        let bytes = decode_fixed_hex(s, 20, "address")?;
+        Ok(Self(format!("0x{}", hex::encode(bytes))))
+    }
+
+    /// Renders this address in EIP-55 mixed-case checksum form
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common::types::Address;
+    ///
+    /// let addr = Address::from_hex("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").unwrap();
+    /// assert_eq!(addr.to_checksummed(), "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+    /// ```
+    pub fn to_checksummed(&self) -> String {
+        let lower = self.0.trim_start_matches("0x").to_lowercase();
+        format!("0x{}", eip55_checksum(&lower))
+    }
+
+    /// Checks whether `s` is a validly EIP-55-checksummed address
+    ///
+    /// Passes only when the hex body equals the recomputed checksum exactly,
+    /// so an all-lowercase or all-uppercase body fails unless the checksum
+    /// itself has that casing (as for a body with no hex letters).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common::types::Address;
+    ///
+    /// assert!(Address::is_valid_checksum("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"));
+    /// assert!(!Address::is_valid_checksum("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"));
+    /// assert!(!Address::is_valid_checksum("not an address"));
+    /// ```
+    pub fn is_valid_checksum(s: &str) -> bool {
+        let Some(hex_part) = s.strip_prefix("0x") else {
+            return false;
+        };
+        if hex_part.len() != 40 || !hex_part.chars().all(|c| c.is_ascii_hexdigit()) {
+            return false;
+        }
+        let lower = hex_part.to_lowercase();
+        hex_part == eip55_checksum(&lower)
+    }
+}
+
 impl fmt::Display for Address {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.0)
@@ -26,12 +143,12 @@ impl fmt::Display for Address {
 }
 
 /// Represents a transaction hash
-/// 
+///
 /// # Examples
-/// 
+///
 /// ```
 /// use common::types::Hash;
-/// 
+///
 /// let hash = Hash("0x8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925".to_string());
 /// assert_eq!(
 ///     hash.to_string(),
@@ -41,6 +158,30 @@ impl fmt::Display for Address {
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Hash(pub String);
 
+impl Hash {
+    /// Parses and validates a hex-encoded 32-byte hash
+    ///
+    /// Requires a `0x` prefix and exactly 32 bytes (64 hex chars). The
+    /// stored value is normalized to lowercase.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common::types::Hash;
+    ///
+    /// let hash = Hash::from_hex(
+    ///     "0x8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925"
+    /// ).unwrap();
+    /// assert_eq!(hash.0.len(), 66);
+    ///
+    /// assert!(Hash::from_hex("0x1234").is_err());
+    /// ```
+    pub fn from_hex(s: &str) -> Result<Self> {
+        let bytes = decode_fixed_hex(s, 32, "hash")?;
+        Ok(Self(format!("0x{}", hex::encode(bytes))))
+    }
+}
+
 impl fmt::Display for Hash {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.0)
@@ -151,6 +292,39 @@ mod tests {
         assert_eq!(hash.to_string(), "0xabc");
     }
 
+    #[test]
+    fn test_address_from_hex_validates_prefix_and_length() {
+        assert!(Address::from_hex("742d35Cc6634C0532925a3b844Bc454e4438f44e").is_err());
+        assert!(Address::from_hex("0x1234").is_err());
+
+        let addr = Address::from_hex("0x742d35Cc6634C0532925a3b844Bc454e4438f44e").unwrap();
+        assert_eq!(addr.0, "0x742d35cc6634c0532925a3b844bc454e4438f44e");
+    }
+
+    #[test]
+    fn test_hash_from_hex_validates_prefix_and_length() {
+        assert!(Hash::from_hex("0x1234").is_err());
+
+        let hash = Hash::from_hex(
+            "0x8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925",
+        )
+        .unwrap();
+        assert_eq!(hash.0.len(), 66);
+    }
+
+    #[test]
+    fn test_eip55_checksum_round_trip() {
+        // Known-good EIP-55 checksummed address from the EIP-55 spec examples.
+        let checksummed = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+        assert!(Address::is_valid_checksum(checksummed));
+
+        let addr = Address::from_hex(checksummed).unwrap();
+        assert_eq!(addr.to_checksummed(), checksummed);
+
+        assert!(!Address::is_valid_checksum(&checksummed.to_lowercase()));
+        assert!(!Address::is_valid_checksum("not an address"));
+    }
+
     #[test]
     fn test_risk_level_ordering() {
         assert!(RiskLevel::None < RiskLevel::Low);
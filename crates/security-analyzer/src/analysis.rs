@@ -0,0 +1,628 @@
+// security-analyzer/src/analysis.rs
+use crate::report::{render_report, ReportFormat};
+use crate::vulnerabilities::{ContractKind, Finding, Severity, VulnerabilityScanner};
+use blockchain_core::blockchain::BlockchainDataProvider;
+use blockchain_core::models::SecurityAnalysis;
+use common::{
+    error::Result,
+    logging::create_timing_span,
+    types::{Address, RiskLevel},
+};
+use futures::future::join_all;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, info, instrument, warn};
+
+/// Default ceiling on how long a single scanner is allowed to run before
+/// `analyze_contract` treats it as failed
+const DEFAULT_SCANNER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Per-severity weight used by `score_findings` to roll a set of findings
+/// up into a single 0-100 risk score, loosely modeled on CVSS severity
+/// bands. `Info`/`None` findings don't move the score.
+fn severity_weight(severity: Severity) -> u32 {
+    match severity {
+        Severity::Critical => 40,
+        Severity::High => 20,
+        Severity::Medium => 8,
+        Severity::Low => 2,
+        Severity::Info | Severity::None => 0,
+    }
+}
+
+/// Aggregates a set of findings into a single weighted risk score on a
+/// 0-100 scale
+///
+/// Sums each finding's severity weight and caps the total at 100, so
+/// enough lower-severity findings add up to the same score as fewer
+/// higher-severity ones, and any total past 100 scores 100.
+///
+/// # Examples
+///
+/// ```
+/// use security_analyzer::analysis::score_findings;
+/// use security_analyzer::vulnerabilities::{Finding, Severity};
+///
+/// let findings = vec![
+///     Finding::new(Severity::Critical, "Reentrancy", "..."),
+///     Finding::new(Severity::Low, "Style nit", "..."),
+/// ];
+/// assert_eq!(score_findings(&findings), 42);
+/// ```
+pub fn score_findings(findings: &[Finding]) -> u8 {
+    let total: u32 = findings.iter().map(|f| severity_weight(f.severity)).sum();
+    total.min(100) as u8
+}
+
+/// Represents a security analyzer that can scan smart contracts for vulnerabilities
+///
+/// The SecurityAnalyzer uses multiple vulnerability scanners to perform comprehensive
+/// security analysis of smart contracts. It includes logging, error handling, and
+/// detailed reporting capabilities.
+///
+/// When constructed with `with_provider`, it fetches the target contract's
+/// deployed bytecode once per `analyze_contract` call via the supplied
+/// `BlockchainDataProvider` and hands the same bytes to every registered
+/// scanner, rather than each scanner re-fetching (or never seeing) code.
+///
+/// # Examples
+///
+/// ```
+/// use security_analyzer::analysis::SecurityAnalyzer;
+/// use common::types::Address;
+///
+/// # tokio_test::block_on(async {
+/// let analyzer = SecurityAnalyzer::new();
+///
+/// let analysis = analyzer.analyze_contract(
+///     &Address("0x742d35Cc6634C0532925a3b844Bc454e4438f44e".to_string())
+/// ).await;
+///
+/// match analysis {
+///     Ok(report) => {
+///         println!("Risk Level: {}", report.risk_level);
+///         println!("Findings: {:?}", report.findings);
+///     }
+///     Err(e) => println!("Analysis failed: {}", e),
+/// }
+/// # })
+/// ```
+pub struct SecurityAnalyzer {
+    /// Scanners run against contracts detected as EVM bytecode
+    scanners: Vec<Box<dyn VulnerabilityScanner>>,
+    /// Scanners run against contracts detected as WASM modules
+    wasm_scanners: Vec<Box<dyn VulnerabilityScanner>>,
+    provider: Option<Arc<dyn BlockchainDataProvider>>,
+    /// Maximum time a single scanner may run before it's treated as failed
+    scanner_timeout: Duration,
+}
+
+impl SecurityAnalyzer {
+    /// Creates a new SecurityAnalyzer instance with no data provider;
+    /// scanners are run against empty bytecode until one is supplied via
+    /// `with_provider`
+    pub fn new() -> Self {
+        info!("Initializing SecurityAnalyzer");
+        Self {
+            scanners: Vec::new(),
+            wasm_scanners: Vec::new(),
+            provider: None,
+            scanner_timeout: DEFAULT_SCANNER_TIMEOUT,
+        }
+    }
+
+    /// Creates a SecurityAnalyzer that fetches real contract bytecode from
+    /// `provider` before running its scanners
+    pub fn with_provider(provider: Arc<dyn BlockchainDataProvider>) -> Self {
+        info!("Initializing SecurityAnalyzer with a blockchain data provider");
+        Self {
+            scanners: Vec::new(),
+            wasm_scanners: Vec::new(),
+            provider: Some(provider),
+            scanner_timeout: DEFAULT_SCANNER_TIMEOUT,
+        }
+    }
+
+    /// Overrides the per-scanner timeout (default 30 seconds)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use security_analyzer::analysis::SecurityAnalyzer;
+    /// use std::time::Duration;
+    ///
+    /// let analyzer = SecurityAnalyzer::new().with_scanner_timeout(Duration::from_secs(5));
+    /// ```
+    pub fn with_scanner_timeout(mut self, timeout: Duration) -> Self {
+        self.scanner_timeout = timeout;
+        self
+    }
+
+    /// Registers a new vulnerability scanner
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use security_analyzer::analysis::SecurityAnalyzer;
+    /// use security_analyzer::vulnerabilities::{Finding, Severity, VulnerabilityScanner};
+    /// use common::error::Result;
+    /// use async_trait::async_trait;
+    /// use common::types::Address;
+    ///
+    /// struct MockScanner;
+    ///
+    /// #[async_trait]
+    /// impl VulnerabilityScanner for MockScanner {
+    ///     async fn scan(&self, _address: &Address, _bytecode: &[u8]) -> Result<Vec<Finding>> {
+    ///         Ok(vec![Finding::new(Severity::None, "No vulnerabilities found", "")])
+    ///     }
+    /// }
+    ///
+    /// let mut analyzer = SecurityAnalyzer::new();
+    /// analyzer.register_scanner(Box::new(MockScanner));
+    /// ```
+    pub fn register_scanner(&mut self, scanner: Box<dyn VulnerabilityScanner>) {
+        debug!("Registering new vulnerability scanner");
+        self.scanners.push(scanner);
+    }
+
+    /// Registers a scanner that only runs against contracts detected as
+    /// WASM modules (see `ContractKind::detect`), rather than EVM bytecode
+    pub fn register_wasm_scanner(&mut self, scanner: Box<dyn VulnerabilityScanner>) {
+        debug!("Registering new WASM vulnerability scanner");
+        self.wasm_scanners.push(scanner);
+    }
+
+    /// Analyzes a smart contract for security vulnerabilities
+    ///
+    /// This method fetches the contract's bytecode once (if a data provider
+    /// is configured), detects whether it's EVM or WASM from its magic
+    /// bytes, then runs the matching set of registered vulnerability
+    /// scanners against that same bytecode and aggregates their findings
+    /// into a comprehensive security report.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address of the smart contract to analyze
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing a SecurityAnalysis if successful, or an Error
+    /// if the analysis fails.
+    #[instrument(skip(self), level = "debug", err)]
+    pub async fn analyze_contract(&self, address: &Address) -> Result<SecurityAnalysis> {
+        self.run_analysis(address).await.map(|(analysis, _)| analysis)
+    }
+
+    /// Analyzes a smart contract and renders the report as `format` (JSON or
+    /// SARIF) instead of the structured `SecurityAnalysis` `analyze_contract`
+    /// returns, for consumers (CI pipelines, code-scanning uploads) that want
+    /// a serialized document rather than a Rust value.
+    #[instrument(skip(self), level = "debug", err)]
+    pub async fn analyze_contract_report(&self, address: &Address, format: ReportFormat) -> Result<String> {
+        let (analysis, findings) = self.run_analysis(address).await?;
+        Ok(render_report(&analysis, &findings, format))
+    }
+
+    /// Shared implementation behind `analyze_contract` and
+    /// `analyze_contract_report`: returns both the flattened
+    /// `SecurityAnalysis` and the structured findings it was built from, so
+    /// callers that need the structured detail (SARIF export) don't have to
+    /// re-run the scan.
+    async fn run_analysis(&self, address: &Address) -> Result<(SecurityAnalysis, Vec<Finding>)> {
+        let _timing_span = create_timing_span("security_analysis", "contract_scan");
+        info!("Starting security analysis for contract {}", address);
+
+        // Fetched once and shared across every scanner below, rather than
+        // each scanner (or none) re-fetching it independently.
+        let bytecode = match &self.provider {
+            Some(provider) => provider.get_code(address).await?,
+            None => Vec::new(),
+        };
+
+        let contract_kind = ContractKind::detect(&bytecode);
+        let scanners = match contract_kind {
+            ContractKind::Evm => &self.scanners,
+            ContractKind::Wasm => &self.wasm_scanners,
+        };
+
+        if scanners.is_empty() {
+            warn!("No vulnerability scanners registered for {:?} contracts", contract_kind);
+            let mut metadata = HashMap::new();
+            metadata.insert(
+                "scan_timestamp".to_string(),
+                common::utils::current_timestamp().to_string(),
+            );
+            metadata.insert("scanner_count".to_string(), "0".to_string());
+            return Ok((
+                SecurityAnalysis {
+                    risk_level: RiskLevel::None,
+                    risk_score: 0,
+                    findings: vec!["No security scanners configured".to_string()],
+                    metadata,
+                },
+                Vec::new(),
+            ));
+        }
+
+        let mut metadata = HashMap::new();
+        metadata.insert(
+            "scan_timestamp".to_string(),
+            common::utils::current_timestamp().to_string(),
+        );
+        metadata.insert("scanner_count".to_string(), scanners.len().to_string());
+
+        // Scanners are independent, so run them concurrently and aggregate
+        // partial failures instead of letting one broken/slow scanner fail
+        // (or stall) the whole report.
+        let scan_futures = scanners.iter().enumerate().map(|(i, scanner)| {
+            let bytecode = &bytecode;
+            async move {
+                (i, tokio::time::timeout(self.scanner_timeout, scanner.scan(address, bytecode)).await)
+            }
+        });
+
+        let mut findings: Vec<Finding> = Vec::new();
+        // Synthetic findings that record an infrastructure failure (a
+        // scanner erroring or timing out), not an actual vulnerability in
+        // the contract. Kept separate from `findings` so they can still
+        // weigh `risk_score` (a partial/degraded report shouldn't score as
+        // a clean one) without promoting `risk_level` — a scanner outage is
+        // not contract risk, and letting it drive the headline risk level
+        // would give CI/dashboards gating on `risk_level` a false signal.
+        let mut scan_failures: Vec<Finding> = Vec::new();
+
+        for (i, outcome) in join_all(scan_futures).await {
+            match outcome {
+                Ok(Ok(scanner_findings)) => {
+                    debug!(
+                        "Scanner {} completed with {} findings",
+                        i,
+                        scanner_findings.len()
+                    );
+                    findings.extend(scanner_findings);
+                }
+                Ok(Err(e)) => {
+                    warn!("Scanner {} failed: {}", i, e);
+                    metadata.insert(format!("scanner_{}_error", i), e.to_string());
+                    // `Severity` has no dedicated `Warn` tier; `Medium` is
+                    // the closest fit (renders as SARIF "warning") and,
+                    // unlike `Info`, carries nonzero weight in
+                    // `score_findings`.
+                    scan_failures.push(Finding::new(
+                        Severity::Medium,
+                        format!("Scanner {} failed", i),
+                        format!("Scanner {} could not complete: {}", i, e),
+                    ));
+                }
+                Err(_elapsed) => {
+                    warn!("Scanner {} timed out after {:?}", i, self.scanner_timeout);
+                    metadata.insert(
+                        format!("scanner_{}_error", i),
+                        format!("timed out after {:?}", self.scanner_timeout),
+                    );
+                    // See the comment on the failure branch above: `Medium`
+                    // stands in for "Warn" so a timed-out scanner still
+                    // registers as a nonzero risk signal.
+                    scan_failures.push(Finding::new(
+                        Severity::Medium,
+                        format!("Scanner {} timed out", i),
+                        format!("Scanner {} did not complete within {:?}", i, self.scanner_timeout),
+                    ));
+                }
+            }
+        }
+
+        // Only real scanner findings drive risk_level; scan_failures are
+        // added below purely for risk_score and the report's finding list.
+        let highest_risk = findings
+            .iter()
+            .map(|f| RiskLevel::from(f.severity))
+            .max()
+            .unwrap_or(RiskLevel::None);
+
+        findings.extend(scan_failures);
+        let risk_score = score_findings(&findings);
+
+        info!(
+            "Analysis complete for contract {}. Risk Level: {}, Risk Score: {}",
+            address, highest_risk, risk_score
+        );
+
+        Ok((
+            SecurityAnalysis {
+                risk_level: highest_risk,
+                risk_score,
+                findings: findings.iter().map(Finding::to_report_string).collect(),
+                metadata,
+            },
+            findings,
+        ))
+    }
+}
+
+impl Default for SecurityAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vulnerabilities::Severity;
+    use async_trait::async_trait;
+    use common::error::Error;
+
+    struct MockScanner {
+        findings: Vec<Finding>,
+    }
+
+    #[async_trait]
+    impl VulnerabilityScanner for MockScanner {
+        async fn scan(&self, _address: &Address, _bytecode: &[u8]) -> Result<Vec<Finding>> {
+            Ok(self.findings.clone())
+        }
+    }
+
+    struct MockCodeScanner;
+
+    #[async_trait]
+    impl VulnerabilityScanner for MockCodeScanner {
+        async fn scan(&self, _address: &Address, bytecode: &[u8]) -> Result<Vec<Finding>> {
+            Ok(vec![Finding::new(
+                Severity::Info,
+                "bytecode length",
+                bytecode.len().to_string(),
+            )])
+        }
+    }
+
+    struct MockProvider {
+        code: Vec<u8>,
+    }
+
+    #[async_trait]
+    impl BlockchainDataProvider for MockProvider {
+        async fn get_transaction(&self, hash: &common::types::Hash) -> Result<blockchain_core::models::Transaction> {
+            Err(Error::NotFound(format!("no transaction {}", hash)))
+        }
+
+        async fn get_contract(&self, address: &Address) -> Result<blockchain_core::models::SmartContract> {
+            Err(Error::NotFound(format!("no contract {}", address)))
+        }
+
+        async fn get_transactions_in_range(
+            &self,
+            _range: common::types::TimeRange,
+        ) -> Result<Vec<blockchain_core::models::Transaction>> {
+            Ok(Vec::new())
+        }
+
+        async fn get_address_transactions(&self, _address: &Address) -> Result<Vec<blockchain_core::models::Transaction>> {
+            Ok(Vec::new())
+        }
+
+        async fn get_balance(&self, _address: &Address) -> Result<u64> {
+            Ok(0)
+        }
+
+        async fn get_nonce(&self, _address: &Address) -> Result<u64> {
+            Ok(0)
+        }
+
+        async fn analyze_contract(&self, _address: &Address) -> Result<SecurityAnalysis> {
+            Ok(SecurityAnalysis {
+                risk_level: RiskLevel::None,
+                risk_score: 0,
+                findings: Vec::new(),
+                metadata: HashMap::new(),
+            })
+        }
+
+        async fn get_code(&self, _address: &Address) -> Result<Vec<u8>> {
+            Ok(self.code.clone())
+        }
+
+        async fn get_storage_at(&self, _address: &Address, _slot: &[u8; 32]) -> Result<[u8; 32]> {
+            Ok([0u8; 32])
+        }
+    }
+
+    #[tokio::test]
+    async fn test_empty_analyzer() {
+        let analyzer = SecurityAnalyzer::new();
+        let address = Address("0x123".to_string());
+
+        let analysis = analyzer.analyze_contract(&address).await.unwrap();
+        assert_eq!(analysis.risk_level, RiskLevel::None);
+        assert!(analysis.findings.contains(&"No security scanners configured".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_risk_level_calculation() {
+        let mut analyzer = SecurityAnalyzer::new();
+
+        analyzer.register_scanner(Box::new(MockScanner {
+            findings: vec![
+                Finding::new(Severity::Critical, "Critical vulnerability found", "..."),
+                Finding::new(Severity::Low, "Low risk issue detected", "..."),
+            ],
+        }));
+
+        let address = Address("0x123".to_string());
+        let analysis = analyzer.analyze_contract(&address).await.unwrap();
+
+        assert_eq!(analysis.risk_level, RiskLevel::Critical);
+        assert_eq!(analysis.findings.len(), 2);
+        assert_eq!(analysis.risk_score, 42);
+    }
+
+    #[test]
+    fn test_score_findings_caps_at_100() {
+        let findings = vec![
+            Finding::new(Severity::Critical, "a", "..."),
+            Finding::new(Severity::Critical, "b", "..."),
+            Finding::new(Severity::Critical, "c", "..."),
+        ];
+        assert_eq!(score_findings(&findings), 100);
+    }
+
+    #[test]
+    fn test_score_findings_ignores_info_and_none() {
+        let findings = vec![
+            Finding::new(Severity::Info, "a", "..."),
+            Finding::new(Severity::None, "b", "..."),
+        ];
+        assert_eq!(score_findings(&findings), 0);
+    }
+
+    #[tokio::test]
+    async fn test_analyze_contract_report_renders_sarif() {
+        let mut analyzer = SecurityAnalyzer::new();
+        analyzer.register_scanner(Box::new(MockScanner {
+            findings: vec![Finding::new(Severity::High, "Missing access control", "...").with_swc_id("SWC-105")],
+        }));
+
+        let address = Address("0x123".to_string());
+        let rendered = analyzer
+            .analyze_contract_report(&address, ReportFormat::Sarif)
+            .await
+            .unwrap();
+
+        assert!(rendered.contains("SWC-105"));
+        assert!(rendered.contains("2.1.0"));
+    }
+
+    #[tokio::test]
+    async fn test_metadata_generation() {
+        let analyzer = SecurityAnalyzer::new();
+        let address = Address("0x123".to_string());
+
+        let analysis = analyzer.analyze_contract(&address).await.unwrap();
+
+        assert!(analysis.metadata.contains_key("scan_timestamp"));
+        assert!(analysis.metadata.contains_key("scanner_count"));
+    }
+
+    #[tokio::test]
+    async fn test_with_provider_fetches_code_once_and_shares_it() {
+        let provider = Arc::new(MockProvider { code: vec![0xde, 0xad, 0xbe, 0xef] });
+        let mut analyzer = SecurityAnalyzer::with_provider(provider);
+        analyzer.register_scanner(Box::new(MockCodeScanner));
+        analyzer.register_scanner(Box::new(MockCodeScanner));
+
+        let address = Address("0x123".to_string());
+        let analysis = analyzer.analyze_contract(&address).await.unwrap();
+
+        assert_eq!(analysis.findings.len(), 2);
+        assert!(analysis.findings.iter().all(|f| f.contains("4")));
+    }
+
+    #[tokio::test]
+    async fn test_without_provider_scans_empty_bytecode() {
+        let mut analyzer = SecurityAnalyzer::new();
+        analyzer.register_scanner(Box::new(MockCodeScanner));
+
+        let address = Address("0x123".to_string());
+        let analysis = analyzer.analyze_contract(&address).await.unwrap();
+
+        assert!(analysis.findings[0].contains("0"));
+    }
+
+    struct FailingScanner;
+
+    #[async_trait]
+    impl VulnerabilityScanner for FailingScanner {
+        async fn scan(&self, _address: &Address, _bytecode: &[u8]) -> Result<Vec<Finding>> {
+            Err(Error::Internal("scanner exploded".to_string()))
+        }
+    }
+
+    struct HangingScanner;
+
+    #[async_trait]
+    impl VulnerabilityScanner for HangingScanner {
+        async fn scan(&self, _address: &Address, _bytecode: &[u8]) -> Result<Vec<Finding>> {
+            tokio::time::sleep(Duration::from_secs(3600)).await;
+            Ok(Vec::new())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_one_failing_scanner_does_not_fail_the_whole_report() {
+        let mut analyzer = SecurityAnalyzer::new();
+        analyzer.register_scanner(Box::new(FailingScanner));
+        analyzer.register_scanner(Box::new(MockScanner {
+            findings: vec![Finding::new(Severity::Medium, "ok scanner finding", "...")],
+        }));
+
+        let address = Address("0x123".to_string());
+        let analysis = analyzer.analyze_contract(&address).await.unwrap();
+
+        assert!(analysis.metadata.contains_key("scanner_0_error"));
+        assert!(analysis.findings.iter().any(|f| f.contains("ok scanner finding")));
+        assert!(analysis.findings.iter().any(|f| f.contains("Scanner 0 failed")));
+    }
+
+    #[tokio::test]
+    async fn test_failing_scanner_contributes_nonzero_risk_score() {
+        let mut analyzer = SecurityAnalyzer::new();
+        analyzer.register_scanner(Box::new(FailingScanner));
+
+        let address = Address("0x123".to_string());
+        let analysis = analyzer.analyze_contract(&address).await.unwrap();
+
+        // A partial/degraded report must not score the same as a clean one...
+        assert!(analysis.risk_score > 0);
+        // ...but a scanner outage is not contract risk, so it must not
+        // promote risk_level on an otherwise-clean contract.
+        assert_eq!(analysis.risk_level, RiskLevel::None);
+    }
+
+    #[tokio::test]
+    async fn test_hung_scanner_times_out_instead_of_stalling_the_report() {
+        let mut analyzer = SecurityAnalyzer::new().with_scanner_timeout(Duration::from_millis(10));
+        analyzer.register_scanner(Box::new(HangingScanner));
+
+        let address = Address("0x123".to_string());
+        let analysis = analyzer.analyze_contract(&address).await.unwrap();
+
+        assert!(analysis.metadata.get("scanner_0_error").unwrap().contains("timed out"));
+        assert!(analysis.findings.iter().any(|f| f.contains("timed out")));
+    }
+
+    #[tokio::test]
+    async fn test_wasm_contract_dispatches_to_wasm_scanners_only() {
+        // Magic + version, no sections: the minimal valid empty WASM module
+        let wasm_code = vec![0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+        let provider = Arc::new(MockProvider { code: wasm_code });
+        let mut analyzer = SecurityAnalyzer::with_provider(provider);
+        analyzer.register_scanner(Box::new(MockCodeScanner));
+        analyzer.register_wasm_scanner(Box::new(MockScanner {
+            findings: vec![Finding::new(Severity::Medium, "wasm finding", "...")],
+        }));
+
+        let address = Address("0x123".to_string());
+        let analysis = analyzer.analyze_contract(&address).await.unwrap();
+
+        assert_eq!(analysis.findings.len(), 1);
+        assert!(analysis.findings[0].contains("wasm finding"));
+    }
+
+    #[tokio::test]
+    async fn test_evm_contract_ignores_registered_wasm_scanners() {
+        let provider = Arc::new(MockProvider { code: vec![0x60, 0x60] });
+        let mut analyzer = SecurityAnalyzer::with_provider(provider);
+        analyzer.register_scanner(Box::new(MockCodeScanner));
+        analyzer.register_wasm_scanner(Box::new(MockScanner {
+            findings: vec![Finding::new(Severity::Medium, "wasm finding", "...")],
+        }));
+
+        let address = Address("0x123".to_string());
+        let analysis = analyzer.analyze_contract(&address).await.unwrap();
+
+        assert!(analysis.findings.iter().all(|f| !f.contains("wasm finding")));
+    }
+}
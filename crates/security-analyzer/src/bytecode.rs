@@ -0,0 +1,334 @@
+// security-analyzer/src/bytecode.rs
+//! EVM bytecode disassembly and basic-block control-flow analysis
+//!
+//! This gives the vulnerability scanners something more than keyword
+//! matching to work with: a flat instruction stream plus a basic-block CFG
+//! that the scanners can walk to decide whether one opcode is actually
+//! reachable from another, rather than just co-occurring somewhere in the
+//! bytecode.
+
+use std::collections::{HashMap, HashSet};
+
+pub const STOP: u8 = 0x00;
+pub const ADD: u8 = 0x01;
+pub const MUL: u8 = 0x02;
+pub const SUB: u8 = 0x03;
+pub const LT: u8 = 0x10;
+pub const GT: u8 = 0x11;
+pub const EQ: u8 = 0x14;
+pub const ISZERO: u8 = 0x15;
+pub const ORIGIN: u8 = 0x32;
+pub const CALLER: u8 = 0x33;
+pub const SLOAD: u8 = 0x54;
+pub const SSTORE: u8 = 0x55;
+pub const JUMP: u8 = 0x56;
+pub const JUMPI: u8 = 0x57;
+pub const JUMPDEST: u8 = 0x5b;
+pub const PUSH1: u8 = 0x60;
+pub const PUSH32: u8 = 0x7f;
+pub const CALL: u8 = 0xf1;
+pub const CALLCODE: u8 = 0xf2;
+pub const RETURN: u8 = 0xf3;
+pub const DELEGATECALL: u8 = 0xf4;
+pub const REVERT: u8 = 0xfd;
+pub const SELFDESTRUCT: u8 = 0xff;
+
+/// A single decoded EVM instruction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    /// Byte offset of this instruction's opcode in the bytecode
+    pub pc: usize,
+    pub opcode: u8,
+    /// `PUSH1..PUSH32` immediate operand, if `opcode` is a push
+    pub immediate: Option<Vec<u8>>,
+}
+
+impl Instruction {
+    /// Whether this is one of the `PUSHn` opcodes
+    pub fn is_push(&self) -> bool {
+        (PUSH1..=PUSH32).contains(&self.opcode)
+    }
+
+    /// Interprets a push immediate as an unsigned jump target; `None` for
+    /// non-push instructions or immediates too wide to fit a `usize`
+    pub fn as_jump_target(&self) -> Option<usize> {
+        let immediate = self.immediate.as_ref()?;
+        if immediate.len() > std::mem::size_of::<usize>() {
+            return None;
+        }
+        let mut value: usize = 0;
+        for byte in immediate {
+            value = (value << 8) | usize::from(*byte);
+        }
+        Some(value)
+    }
+
+    /// Whether this opcode unconditionally ends a basic block (no
+    /// fall-through successor)
+    pub fn terminates_block(&self) -> bool {
+        matches!(
+            self.opcode,
+            STOP | JUMP | RETURN | REVERT | SELFDESTRUCT
+        )
+    }
+}
+
+/// Disassembles EVM runtime bytecode into its flat instruction stream
+///
+/// `PUSH1..PUSH32` immediates are consumed as operand bytes rather than
+/// decoded as further opcodes; an immediate that runs past the end of
+/// `code` is truncated to whatever bytes remain, matching real EVM
+/// behaviour for truncated bytecode.
+///
+/// # Examples
+///
+/// ```
+/// use security_analyzer::bytecode::disassemble;
+///
+/// // PUSH1 0x05, JUMP
+/// let instructions = disassemble(&[0x60, 0x05, 0x56]);
+/// assert_eq!(instructions.len(), 2);
+/// assert_eq!(instructions[0].immediate, Some(vec![0x05]));
+/// ```
+pub fn disassemble(code: &[u8]) -> Vec<Instruction> {
+    let mut instructions = Vec::new();
+    let mut pc = 0usize;
+
+    while pc < code.len() {
+        let opcode = code[pc];
+        let immediate = if (PUSH1..=PUSH32).contains(&opcode) {
+            let push_len = usize::from(opcode - PUSH1 + 1);
+            let start = pc + 1;
+            let end = (start + push_len).min(code.len());
+            Some(code[start..end].to_vec())
+        } else {
+            None
+        };
+
+        let consumed = 1 + immediate.as_ref().map_or(0, Vec::len);
+        instructions.push(Instruction { pc, opcode, immediate });
+        pc += consumed;
+    }
+
+    instructions
+}
+
+/// A straight-line run of instructions with no internal jump targets
+#[derive(Debug, Clone)]
+pub struct BasicBlock {
+    pub start_pc: usize,
+    pub instructions: Vec<Instruction>,
+}
+
+impl BasicBlock {
+    /// `pc` one past the last instruction in this block
+    pub fn end_pc(&self) -> usize {
+        self.instructions
+            .last()
+            .map_or(self.start_pc, |last| last.pc + 1 + last.immediate.as_ref().map_or(0, Vec::len))
+    }
+}
+
+/// A basic-block control-flow graph reconstructed from a disassembled
+/// instruction stream
+///
+/// Blocks split on `JUMP`/`JUMPI`/`JUMPDEST` boundaries; edges are resolved
+/// statically from a `PUSHn` immediately preceding a `JUMP`/`JUMPI` (the
+/// overwhelmingly common pattern emitted by Solidity's compiler for direct
+/// jumps). Dynamic jump targets computed at runtime can't be resolved this
+/// way and simply produce no outgoing edge.
+///
+/// # Examples
+///
+/// ```
+/// use security_analyzer::bytecode::{disassemble, ControlFlowGraph};
+///
+/// // JUMPDEST, PUSH1 0x00, JUMPI, JUMPDEST, STOP
+/// let code = [0x5b, 0x60, 0x00, 0x57, 0x5b, 0x00];
+/// let cfg = ControlFlowGraph::build(&disassemble(&code));
+/// assert_eq!(cfg.blocks.len(), 2);
+/// ```
+pub struct ControlFlowGraph {
+    pub blocks: Vec<BasicBlock>,
+    /// `edges[i]` holds the indices of blocks reachable directly from block `i`
+    pub edges: Vec<Vec<usize>>,
+}
+
+impl ControlFlowGraph {
+    /// Builds a CFG from an already-disassembled instruction stream
+    pub fn build(instructions: &[Instruction]) -> Self {
+        let blocks = Self::split_blocks(instructions);
+        let block_by_start: HashMap<usize, usize> = blocks
+            .iter()
+            .enumerate()
+            .map(|(i, block)| (block.start_pc, i))
+            .collect();
+
+        let edges = blocks
+            .iter()
+            .enumerate()
+            .map(|(i, block)| Self::successors(block, i, &blocks, &block_by_start))
+            .collect();
+
+        Self { blocks, edges }
+    }
+
+    fn split_blocks(instructions: &[Instruction]) -> Vec<BasicBlock> {
+        let mut blocks = Vec::new();
+        let mut current: Vec<Instruction> = Vec::new();
+
+        for instruction in instructions {
+            if instruction.opcode == JUMPDEST && !current.is_empty() {
+                blocks.push(BasicBlock {
+                    start_pc: current[0].pc,
+                    instructions: std::mem::take(&mut current),
+                });
+            }
+
+            let ends_block = matches!(instruction.opcode, JUMP | JUMPI) || instruction.terminates_block();
+            current.push(instruction.clone());
+
+            if ends_block {
+                blocks.push(BasicBlock {
+                    start_pc: current[0].pc,
+                    instructions: std::mem::take(&mut current),
+                });
+            }
+        }
+
+        if !current.is_empty() {
+            blocks.push(BasicBlock {
+                start_pc: current[0].pc,
+                instructions: current,
+            });
+        }
+
+        blocks
+    }
+
+    fn successors(
+        block: &BasicBlock,
+        index: usize,
+        blocks: &[BasicBlock],
+        block_by_start: &HashMap<usize, usize>,
+    ) -> Vec<usize> {
+        let Some(last) = block.instructions.last() else {
+            return Vec::new();
+        };
+
+        let mut successors = Vec::new();
+
+        if matches!(last.opcode, JUMP | JUMPI) {
+            // The overwhelmingly common compiler pattern pushes the target
+            // immediately before the jump.
+            let preceding_push = block.instructions.iter().rev().nth(1);
+            if let Some(target) = preceding_push.and_then(Instruction::as_jump_target) {
+                if let Some(&target_block) = block_by_start.get(&target) {
+                    successors.push(target_block);
+                }
+            }
+        }
+
+        let falls_through = last.opcode == JUMPI || !last.terminates_block();
+        if falls_through && index + 1 < blocks.len() {
+            successors.push(index + 1);
+        }
+
+        successors
+    }
+
+    /// Index of the block containing `pc`, if any
+    pub fn block_containing(&self, pc: usize) -> Option<usize> {
+        self.blocks
+            .iter()
+            .position(|block| pc >= block.start_pc && pc < block.end_pc())
+    }
+
+    /// Whether `to_pc` is reachable from `from_pc` by following zero or more
+    /// edges, including when both belong to the same block
+    pub fn is_reachable(&self, from_pc: usize, to_pc: usize) -> bool {
+        let Some(from_block) = self.block_containing(from_pc) else {
+            return false;
+        };
+        let Some(to_block) = self.block_containing(to_pc) else {
+            return false;
+        };
+        if from_block == to_block {
+            return to_pc >= from_pc;
+        }
+
+        let mut visited: HashSet<usize> = HashSet::new();
+        let mut stack = vec![from_block];
+        while let Some(current) = stack.pop() {
+            if current == to_block {
+                return true;
+            }
+            if !visited.insert(current) {
+                continue;
+            }
+            stack.extend(self.edges[current].iter().copied());
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disassemble_decodes_push_immediates() {
+        // PUSH2 0xDEAD, ADD
+        let instructions = disassemble(&[0x61, 0xde, 0xad, 0x01]);
+        assert_eq!(instructions.len(), 2);
+        assert_eq!(instructions[0].opcode, 0x61);
+        assert_eq!(instructions[0].immediate, Some(vec![0xde, 0xad]));
+        assert_eq!(instructions[1].pc, 3);
+        assert_eq!(instructions[1].opcode, ADD);
+    }
+
+    #[test]
+    fn test_disassemble_truncates_push_past_end_of_code() {
+        // PUSH4 with only 2 bytes remaining
+        let instructions = disassemble(&[0x63, 0x01, 0x02]);
+        assert_eq!(instructions.len(), 1);
+        assert_eq!(instructions[0].immediate, Some(vec![0x01, 0x02]));
+    }
+
+    #[test]
+    fn test_as_jump_target_reads_big_endian_immediate() {
+        let push = Instruction { pc: 0, opcode: PUSH1, immediate: Some(vec![0x05]) };
+        assert_eq!(push.as_jump_target(), Some(5));
+
+        let push2 = Instruction { pc: 0, opcode: 0x61, immediate: Some(vec![0x01, 0x00]) };
+        assert_eq!(push2.as_jump_target(), Some(256));
+    }
+
+    #[test]
+    fn test_cfg_splits_on_jumpdest_and_jumpi() {
+        // JUMPDEST, PUSH1 0x00, JUMPI, JUMPDEST, STOP
+        let code = [JUMPDEST, PUSH1, 0x00, JUMPI, JUMPDEST, STOP];
+        let cfg = ControlFlowGraph::build(&disassemble(&code));
+        assert_eq!(cfg.blocks.len(), 2);
+        assert_eq!(cfg.blocks[0].start_pc, 0);
+        assert_eq!(cfg.blocks[1].start_pc, 4);
+    }
+
+    #[test]
+    fn test_cfg_resolves_static_jump_target() {
+        // PUSH1 0x03, JUMP, JUMPDEST, STOP (JUMPDEST sits at pc 3)
+        let code = [PUSH1, 0x03, JUMP, JUMPDEST, STOP];
+        let cfg = ControlFlowGraph::build(&disassemble(&code));
+        assert_eq!(cfg.blocks.len(), 2);
+        assert_eq!(cfg.edges[0], vec![1]);
+    }
+
+    #[test]
+    fn test_cfg_is_reachable_across_blocks() {
+        // PUSH1 0x04, JUMPI, STOP, JUMPDEST, SSTORE (JUMPDEST sits at pc 4)
+        let code = [PUSH1, 0x04, JUMPI, STOP, JUMPDEST, SSTORE];
+        let cfg = ControlFlowGraph::build(&disassemble(&code));
+        assert!(cfg.is_reachable(0, 5));
+        assert!(!cfg.is_reachable(5, 0));
+    }
+}
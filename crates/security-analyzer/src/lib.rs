@@ -0,0 +1,25 @@
+// security-analyzer/src/lib.rs
+//! Smart contract vulnerability scanning
+//!
+//! This crate provides a pluggable architecture for scanning smart contracts
+//! and transactions for known vulnerability classes, built on a registry of
+//! reusable `VulnerabilityRule`s.
+
+pub mod analysis;
+pub mod bytecode;
+pub mod report;
+pub mod rules;
+pub mod vulnerabilities;
+pub mod wasm;
+
+// Re-export main types
+pub use analysis::{score_findings, SecurityAnalyzer};
+pub use bytecode::{disassemble, BasicBlock, ControlFlowGraph, Instruction};
+pub use report::{render_report, ReportFormat};
+pub use rules::{built_in_rules, RuleFinding, RuleRegistry, VulnerabilityRule};
+pub use vulnerabilities::{
+    AccessControlScanner, CodeLocation, ContractKind, Finding, IntegerOverflowScanner,
+    ReentrancyScanner, RuleBasedScanner, Severity, VulnerabilityScanner, WasmImportScanner,
+    WasmResourceScanner,
+};
+pub use wasm::{parse as parse_wasm_module, WasmModule};
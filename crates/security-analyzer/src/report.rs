@@ -0,0 +1,166 @@
+// security-analyzer/src/report.rs
+//! JSON and SARIF 2.1.0 export of a security analysis
+//!
+//! SARIF (Static Analysis Results Interchange Format) is what GitHub code
+//! scanning and similar CI tooling expect; this module maps each
+//! structured `Finding` onto a SARIF `result` so a contract scan can be
+//! uploaded there directly, rather than only read by a human off
+//! `SecurityAnalysis.findings`'s flattened strings.
+
+use crate::vulnerabilities::{CodeLocation, Finding, Severity};
+use blockchain_core::models::SecurityAnalysis;
+use serde_json::{json, Value};
+
+/// Output format for a rendered security report
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// A plain JSON document carrying the full structured findings
+    Json,
+    /// A SARIF 2.1.0 `runs`/`results` document
+    Sarif,
+}
+
+/// Renders `analysis` and its originating structured `findings` as `format`
+///
+/// `findings` is needed alongside `analysis` because `SecurityAnalysis.findings`
+/// has already flattened each `Finding` down to a free-text string by the
+/// time a `SecurityAnalysis` exists; SARIF needs the SWC id, severity, and
+/// location that only the structured form carries.
+///
+/// # Examples
+///
+/// ```
+/// use security_analyzer::report::{render_report, ReportFormat};
+/// use security_analyzer::vulnerabilities::{Finding, Severity};
+/// use blockchain_core::models::SecurityAnalysis;
+/// use common::types::RiskLevel;
+/// use std::collections::HashMap;
+///
+/// let finding = Finding::new(Severity::High, "Missing access control", "...").with_swc_id("SWC-105");
+/// let analysis = SecurityAnalysis {
+///     risk_level: RiskLevel::High,
+///     risk_score: 20,
+///     findings: vec![finding.to_report_string()],
+///     metadata: HashMap::new(),
+/// };
+///
+/// let sarif = render_report(&analysis, &[finding], ReportFormat::Sarif);
+/// assert!(sarif.contains("SWC-105"));
+/// ```
+pub fn render_report(analysis: &SecurityAnalysis, findings: &[Finding], format: ReportFormat) -> String {
+    match format {
+        ReportFormat::Json => render_json(analysis, findings),
+        ReportFormat::Sarif => render_sarif(findings).to_string(),
+    }
+}
+
+fn render_json(analysis: &SecurityAnalysis, findings: &[Finding]) -> String {
+    let document = json!({
+        "risk_level": analysis.risk_level.to_string(),
+        "risk_score": analysis.risk_score,
+        "metadata": analysis.metadata,
+        "findings": findings,
+    });
+    serde_json::to_string_pretty(&document).unwrap_or_else(|e| format!("{{\"error\": \"{}\"}}", e))
+}
+
+fn sarif_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical | Severity::High => "error",
+        Severity::Medium => "warning",
+        Severity::Low | Severity::Info | Severity::None => "note",
+    }
+}
+
+fn sarif_location(location: &Option<CodeLocation>) -> Option<Value> {
+    let physical_location = match location.as_ref()? {
+        CodeLocation::Source { file, line } => json!({
+            "artifactLocation": { "uri": file },
+            "region": { "startLine": line },
+        }),
+        CodeLocation::Bytecode { offset } => json!({
+            "artifactLocation": { "uri": "bytecode" },
+            "region": { "byteOffset": offset },
+        }),
+    };
+    Some(json!({ "physicalLocation": physical_location }))
+}
+
+fn render_sarif(findings: &[Finding]) -> Value {
+    let results: Vec<Value> = findings
+        .iter()
+        .map(|finding| {
+            let mut result = json!({
+                "ruleId": finding.swc_id.clone().unwrap_or_else(|| "uncategorized".to_string()),
+                "level": sarif_level(finding.severity),
+                "message": { "text": format!("{}: {}", finding.title, finding.description) },
+            });
+            if let Some(location) = sarif_location(&finding.location) {
+                result["locations"] = json!([location]);
+            }
+            result
+        })
+        .collect();
+
+    json!({
+        "version": "2.1.0",
+        "$schema": "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "scrutiny-chain-security-analyzer",
+                    "rules": []
+                }
+            },
+            "results": results,
+        }],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn sample_analysis() -> SecurityAnalysis {
+        SecurityAnalysis {
+            risk_level: common::types::RiskLevel::High,
+            risk_score: 20,
+            findings: vec!["High: Missing access control (SWC-105) - ...".to_string()],
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_render_json_includes_risk_score_and_findings() {
+        let finding = Finding::new(Severity::High, "Missing access control", "...").with_swc_id("SWC-105");
+        let rendered = render_report(&sample_analysis(), &[finding], ReportFormat::Json);
+
+        assert!(rendered.contains("\"risk_score\": 20"));
+        assert!(rendered.contains("SWC-105"));
+    }
+
+    #[test]
+    fn test_render_sarif_maps_swc_id_to_rule_id_and_severity_to_level() {
+        let finding = Finding::new(Severity::Critical, "Reentrancy", "...")
+            .with_swc_id("SWC-107")
+            .with_location(CodeLocation::Bytecode { offset: 12 });
+        let rendered = render_report(&sample_analysis(), &[finding], ReportFormat::Sarif);
+        let document: Value = serde_json::from_str(&rendered).unwrap();
+
+        let result = &document["runs"][0]["results"][0];
+        assert_eq!(result["ruleId"], "SWC-107");
+        assert_eq!(result["level"], "error");
+        assert_eq!(result["locations"][0]["physicalLocation"]["region"]["byteOffset"], 12);
+    }
+
+    #[test]
+    fn test_render_sarif_uses_uncategorized_rule_id_when_no_swc_id() {
+        let finding = Finding::new(Severity::Low, "Style nit", "...");
+        let rendered = render_report(&sample_analysis(), &[finding], ReportFormat::Sarif);
+        let document: Value = serde_json::from_str(&rendered).unwrap();
+
+        assert_eq!(document["runs"][0]["results"][0]["ruleId"], "uncategorized");
+        assert_eq!(document["runs"][0]["results"][0]["level"], "note");
+    }
+}
@@ -0,0 +1,279 @@
+// security-analyzer/src/rules.rs
+use blockchain_core::models::Transaction;
+use common::types::RiskLevel;
+use std::collections::HashSet;
+
+/// A single finding produced when a `VulnerabilityRule` matches
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleFinding {
+    /// `id()` of the rule that matched
+    pub rule_id: String,
+    /// Severity assigned by the matching rule
+    pub risk: RiskLevel,
+    /// Human-readable description of the match
+    pub detail: String,
+}
+
+/// A reusable, versioned detection rule for a known exploit class
+///
+/// Rules are intentionally narrow and stateless: each one inspects a
+/// transaction (and, when available, the target contract's bytecode) for a
+/// single known vulnerability signature. New exploit classes are added by
+/// implementing this trait and registering an instance with a
+/// `RuleRegistry`, instead of editing the scanner itself.
+pub trait VulnerabilityRule: Send + Sync {
+    /// Stable identifier for this rule, e.g. `"unprotected-delegatecall"`
+    fn id(&self) -> &str;
+
+    /// Severity assigned when this rule matches
+    fn risk(&self) -> RiskLevel;
+
+    /// Human-readable description of what matching means; used to build a
+    /// `RuleFinding`'s `detail` text
+    fn description(&self) -> &str;
+
+    /// Tests whether `tx` (and, when available, the target contract's
+    /// `bytecode`) triggers this rule
+    fn matches(&self, tx: &Transaction, bytecode: Option<&[u8]>) -> bool;
+}
+
+/// Holds the set of `VulnerabilityRule`s a scanner evaluates during a scan
+///
+/// # Examples
+///
+/// ```
+/// use security_analyzer::rules::{built_in_rules, RuleRegistry};
+///
+/// let registry = RuleRegistry::with_rules(built_in_rules());
+/// assert!(registry.len() >= 2);
+/// ```
+pub struct RuleRegistry {
+    rules: Vec<Box<dyn VulnerabilityRule>>,
+}
+
+impl RuleRegistry {
+    /// Creates an empty registry
+    pub fn new() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Creates a registry pre-loaded with `rules`
+    pub fn with_rules(rules: Vec<Box<dyn VulnerabilityRule>>) -> Self {
+        Self { rules }
+    }
+
+    /// Registers an additional rule
+    pub fn register(&mut self, rule: Box<dyn VulnerabilityRule>) {
+        self.rules.push(rule);
+    }
+
+    /// Number of rules currently loaded
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Whether the registry has no rules loaded
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Evaluates every loaded rule against `tx`/`bytecode`, returning a
+    /// `RuleFinding` for each rule that matches
+    pub fn evaluate(&self, tx: &Transaction, bytecode: Option<&[u8]>) -> Vec<RuleFinding> {
+        self.rules
+            .iter()
+            .filter(|rule| rule.matches(tx, bytecode))
+            .map(|rule| RuleFinding {
+                rule_id: rule.id().to_string(),
+                risk: rule.risk(),
+                detail: rule.description().to_string(),
+            })
+            .collect()
+    }
+}
+
+impl Default for RuleRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Detects a `DELEGATECALL` with no preceding ownership/initialization
+/// guard, in a transaction whose input data carries at least a 4-byte
+/// function selector — the root cause
+/// behind several real multi-signature wallet compromises (e.g. the Parity
+/// multisig incidents), where an uninitialized or unprotected
+/// `delegatecall` let an attacker take ownership of the wallet's logic
+/// contract.
+pub struct UnprotectedDelegatecallRule;
+
+impl VulnerabilityRule for UnprotectedDelegatecallRule {
+    fn id(&self) -> &str {
+        "unprotected-delegatecall"
+    }
+
+    fn risk(&self) -> RiskLevel {
+        RiskLevel::Critical
+    }
+
+    fn description(&self) -> &str {
+        "Bytecode performs a DELEGATECALL with no preceding storage-guard check, so an \
+         uninitialized or unprotected call can hand ownership to the caller"
+    }
+
+    fn matches(&self, tx: &Transaction, bytecode: Option<&[u8]>) -> bool {
+        const DELEGATECALL: u8 = 0xf4;
+        const SLOAD: u8 = 0x54;
+        const JUMPI: u8 = 0x57;
+        const MIN_CALLDATA_LEN: usize = 4;
+
+        let Some(code) = bytecode else {
+            return false;
+        };
+        let Some(delegatecall_at) = code.iter().position(|&b| b == DELEGATECALL) else {
+            return false;
+        };
+
+        // A guarded delegatecall proxy typically checks an "initialized"
+        // storage flag (SLOAD followed by a conditional jump) before the
+        // DELEGATECALL; treat its absence as unprotected.
+        let has_storage_guard = code[..delegatecall_at].windows(2).any(|w| w == [SLOAD, JUMPI]);
+        !has_storage_guard && tx.data.len() >= MIN_CALLDATA_LEN
+    }
+}
+
+/// Detects bytecode that contains a `SELFDESTRUCT` byte and no `CALLER`
+/// (`msg.sender`) byte anywhere, a sign that any caller can destroy the
+/// contract. The raw bytes are scanned without disassembly, so whether the
+/// `SELFDESTRUCT` is reachable is not checked.
+pub struct ReachableSelfdestructRule;
+
+impl VulnerabilityRule for ReachableSelfdestructRule {
+    fn id(&self) -> &str {
+        "reachable-selfdestruct"
+    }
+
+    fn risk(&self) -> RiskLevel {
+        RiskLevel::Critical
+    }
+
+    fn description(&self) -> &str {
+        "Bytecode contains a SELFDESTRUCT with no CALLER check guarding it"
+    }
+
+    fn matches(&self, _tx: &Transaction, bytecode: Option<&[u8]>) -> bool {
+        const SELFDESTRUCT: u8 = 0xff;
+        const CALLER: u8 = 0x33;
+
+        let Some(code) = bytecode else {
+            return false;
+        };
+        code.contains(&SELFDESTRUCT) && !code.contains(&CALLER)
+    }
+}
+
+/// Detects a transaction whose `to` address appears in a configurable
+/// blacklist (e.g. known mixer/exploit contracts)
+pub struct BlacklistedAddressRule {
+    blacklist: HashSet<String>,
+}
+
+impl BlacklistedAddressRule {
+    /// Creates a rule that flags transactions addressed to any entry in
+    /// `blacklist`
+    pub fn new(blacklist: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            blacklist: blacklist.into_iter().map(|a| a.to_lowercase()).collect(),
+        }
+    }
+}
+
+impl VulnerabilityRule for BlacklistedAddressRule {
+    fn id(&self) -> &str {
+        "blacklisted-address"
+    }
+
+    fn risk(&self) -> RiskLevel {
+        RiskLevel::High
+    }
+
+    fn description(&self) -> &str {
+        "Transaction interacts with an address on the configured blacklist"
+    }
+
+    fn matches(&self, tx: &Transaction, _bytecode: Option<&[u8]>) -> bool {
+        tx.to
+            .as_ref()
+            .map_or(false, |to| self.blacklist.contains(&to.0.to_lowercase()))
+    }
+}
+
+/// Returns the scanner's default built-in rule set: unprotected
+/// delegatecall and reachable selfdestruct. The address blacklist isn't
+/// included here since it needs configuration — add a `BlacklistedAddressRule`
+/// via `RuleRegistry::register` for that.
+pub fn built_in_rules() -> Vec<Box<dyn VulnerabilityRule>> {
+    vec![
+        Box::new(UnprotectedDelegatecallRule),
+        Box::new(ReachableSelfdestructRule),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::types::{Address, Hash};
+
+    fn sample_tx(data: Vec<u8>) -> Transaction {
+        Transaction::new(
+            Hash("0x123".to_string()),
+            Address("0xabc".to_string()),
+            Some(Address("0xdef".to_string())),
+            1000,
+            50,
+            21000,
+            5,
+            data,
+        )
+    }
+
+    #[test]
+    fn test_unprotected_delegatecall_rule_requires_missing_guard() {
+        let rule = UnprotectedDelegatecallRule;
+        let tx = sample_tx(vec![0xde, 0xad, 0xbe, 0xef]);
+
+        assert!(!rule.matches(&tx, None));
+        assert!(rule.matches(&tx, Some(&[0xf4])));
+        assert!(!rule.matches(&tx, Some(&[0x54, 0x57, 0xf4])));
+    }
+
+    #[test]
+    fn test_reachable_selfdestruct_rule() {
+        let rule = ReachableSelfdestructRule;
+        let tx = sample_tx(vec![]);
+
+        assert!(rule.matches(&tx, Some(&[0xff])));
+        assert!(!rule.matches(&tx, Some(&[0x33, 0xff])));
+        assert!(!rule.matches(&tx, None));
+    }
+
+    #[test]
+    fn test_blacklisted_address_rule() {
+        let rule = BlacklistedAddressRule::new(vec!["0xDEF".to_string()]);
+        let tx = sample_tx(vec![]);
+
+        assert!(rule.matches(&tx, None));
+        assert!(!BlacklistedAddressRule::new(vec!["0xnotlisted".to_string()]).matches(&tx, None));
+    }
+
+    #[test]
+    fn test_registry_evaluates_only_matching_rules() {
+        let registry = RuleRegistry::with_rules(built_in_rules());
+        let tx = sample_tx(vec![0xde, 0xad, 0xbe, 0xef]);
+
+        let findings = registry.evaluate(&tx, Some(&[0xf4]));
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "unprotected-delegatecall");
+        assert_eq!(findings[0].risk, RiskLevel::Critical);
+    }
+}
@@ -0,0 +1,947 @@
+// security-analyzer/src/vulnerabilities.rs
+use async_trait::async_trait;
+use blockchain_core::models::Transaction;
+use common::{
+    error::Result,
+    types::{Address, RiskLevel},
+};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use tracing::{debug, info};
+
+use crate::bytecode;
+use crate::rules::{built_in_rules, RuleFinding, RuleRegistry, VulnerabilityRule};
+use crate::wasm;
+use std::collections::HashSet;
+
+/// Severity of a `Finding`, ordered from least to most severe
+///
+/// Unlike `common::types::RiskLevel`, `Severity` has an `Info` variant for
+/// purely informational findings (best-practice suggestions, observations)
+/// that shouldn't influence a contract's overall risk level.
+///
+/// # Examples
+///
+/// ```
+/// use security_analyzer::vulnerabilities::Severity;
+///
+/// assert!(Severity::Info < Severity::Low);
+/// assert!(Severity::Critical > Severity::High);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Severity {
+    None,
+    Info,
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::None => write!(f, "None"),
+            Severity::Info => write!(f, "Info"),
+            Severity::Low => write!(f, "Low"),
+            Severity::Medium => write!(f, "Medium"),
+            Severity::High => write!(f, "High"),
+            Severity::Critical => write!(f, "Critical"),
+        }
+    }
+}
+
+impl From<Severity> for RiskLevel {
+    /// Collapses `Severity` onto `RiskLevel` for consumers (like
+    /// `blockchain_core::models::SecurityAnalysis`) that only know about the
+    /// coarser risk scale; `Info` has no risk-bearing equivalent and maps to
+    /// `RiskLevel::None`.
+    fn from(severity: Severity) -> Self {
+        match severity {
+            Severity::None | Severity::Info => RiskLevel::None,
+            Severity::Low => RiskLevel::Low,
+            Severity::Medium => RiskLevel::Medium,
+            Severity::High => RiskLevel::High,
+            Severity::Critical => RiskLevel::Critical,
+        }
+    }
+}
+
+impl From<RiskLevel> for Severity {
+    fn from(risk: RiskLevel) -> Self {
+        match risk {
+            RiskLevel::None => Severity::None,
+            RiskLevel::Low => Severity::Low,
+            RiskLevel::Medium => Severity::Medium,
+            RiskLevel::High => Severity::High,
+            RiskLevel::Critical => Severity::Critical,
+        }
+    }
+}
+
+/// The executable format of a contract's deployed code
+///
+/// `SecurityAnalyzer` detects this from the fetched bytecode's leading
+/// bytes and uses it to pick which set of registered scanners to run.
+///
+/// # Examples
+///
+/// ```
+/// use security_analyzer::vulnerabilities::ContractKind;
+///
+/// assert_eq!(ContractKind::detect(&[0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00]), ContractKind::Wasm);
+/// assert_eq!(ContractKind::detect(&[0x60, 0x60, 0x60, 0x40]), ContractKind::Evm);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContractKind {
+    Evm,
+    Wasm,
+}
+
+impl ContractKind {
+    /// Detects a contract's executable format from its fetched bytecode;
+    /// anything not starting with the WASM magic bytes is treated as EVM
+    pub fn detect(bytecode: &[u8]) -> Self {
+        if bytecode.starts_with(&wasm::WASM_MAGIC) {
+            ContractKind::Wasm
+        } else {
+            ContractKind::Evm
+        }
+    }
+}
+
+/// Where a `Finding` applies: a location in the original source, or a raw
+/// bytecode offset when no source mapping is available
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CodeLocation {
+    Source { file: String, line: u32 },
+    Bytecode { offset: usize },
+}
+
+/// A single, structured vulnerability finding produced by a `VulnerabilityScanner`
+///
+/// Replaces free-text scan results with a machine-readable shape: a
+/// `severity` on a fixed scale, an optional stable SWC (Smart Contract
+/// Weakness Classification) identifier such as `"SWC-107"`, and an optional
+/// `location` pinpointing where the issue was observed.
+///
+/// # Examples
+///
+/// ```
+/// use security_analyzer::vulnerabilities::{Finding, Severity};
+///
+/// let finding = Finding::new(Severity::High, "Missing access control", "...")
+///     .with_swc_id("SWC-105");
+///
+/// assert_eq!(finding.to_report_string(), "High: Missing access control (SWC-105) - ...");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Finding {
+    pub severity: Severity,
+    pub swc_id: Option<String>,
+    pub title: String,
+    pub description: String,
+    pub location: Option<CodeLocation>,
+}
+
+impl Finding {
+    /// Creates a finding with no SWC classification or location set
+    pub fn new(severity: Severity, title: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            severity,
+            swc_id: None,
+            title: title.into(),
+            description: description.into(),
+            location: None,
+        }
+    }
+
+    /// Attaches an SWC (Smart Contract Weakness Classification) identifier
+    pub fn with_swc_id(mut self, swc_id: impl Into<String>) -> Self {
+        self.swc_id = Some(swc_id.into());
+        self
+    }
+
+    /// Attaches a source or bytecode location
+    pub fn with_location(mut self, location: CodeLocation) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// Renders this finding as a single line of text, for consumers that
+    /// still expect scan results as free-form strings
+    pub fn to_report_string(&self) -> String {
+        match &self.swc_id {
+            Some(swc_id) => format!(
+                "{}: {} ({}) - {}",
+                self.severity, self.title, swc_id, self.description
+            ),
+            None => format!("{}: {} - {}", self.severity, self.title, self.description),
+        }
+    }
+}
+
+impl From<RuleFinding> for Finding {
+    fn from(rule_finding: RuleFinding) -> Self {
+        Finding::new(rule_finding.risk.into(), rule_finding.rule_id, rule_finding.detail)
+    }
+}
+
+/// Trait defining the interface for vulnerability scanners
+///
+/// Vulnerability scanners analyze smart contracts for specific types of security issues.
+/// Implementations of this trait should focus on specific vulnerability types like:
+/// - Reentrancy
+/// - Integer overflow/underflow
+/// - Access control issues
+/// - Denial of service vectors
+/// - etc.
+///
+/// # Examples
+/// ```
+/// use security_analyzer::vulnerabilities::{Finding, Severity, VulnerabilityScanner};
+/// use common::types::Address;
+/// use common::error::Result;
+/// use async_trait::async_trait;
+///
+/// struct ReentrancyScanner;
+///
+/// #[async_trait]
+/// impl VulnerabilityScanner for ReentrancyScanner {
+///     async fn scan(&self, address: &Address, _bytecode: &[u8]) -> Result<Vec<Finding>> {
+///         // Real implementation would analyze contract bytecode/source
+///         Ok(vec![Finding::new(Severity::None, "No issues found", "")])
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait VulnerabilityScanner: Send + Sync {
+    /// Scans a smart contract for vulnerabilities
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address of the smart contract to scan
+    /// * `bytecode` - The contract's deployed runtime bytecode, fetched once
+    ///   per analysis run by whoever orchestrates the scan (e.g.
+    ///   `SecurityAnalyzer`) and shared across every registered scanner
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing the structured findings from the scan,
+    /// or an Error if the scan fails.
+    async fn scan(&self, address: &Address, bytecode: &[u8]) -> Result<Vec<Finding>>;
+}
+
+/// Detects the classic checks-effects-interactions violation: a `CALL` or
+/// `CALLCODE` followed, on a path reachable in the contract's control-flow
+/// graph, by an `SSTORE` before the call's success is checked
+///
+/// This is a static approximation, not full symbolic execution: it doesn't
+/// track stack values, so it can't confirm the call actually carries a
+/// nonzero value or that the `SSTORE` targets the same storage slot a
+/// later read depends on. It flags the pattern itself, which is still the
+/// dominant real-world signature (e.g. the DAO exploit) and a useful
+/// starting point for manual review.
+pub struct ReentrancyScanner;
+
+fn find_reentrancy_findings(bytecode_bytes: &[u8]) -> Vec<Finding> {
+    let instructions = bytecode::disassemble(bytecode_bytes);
+    let cfg = bytecode::ControlFlowGraph::build(&instructions);
+
+    let mut findings = Vec::new();
+    for call in instructions
+        .iter()
+        .filter(|i| matches!(i.opcode, bytecode::CALL | bytecode::CALLCODE))
+    {
+        let mut success_checked = false;
+        for candidate in instructions.iter().filter(|i| i.pc > call.pc) {
+            if !cfg.is_reachable(call.pc, candidate.pc) {
+                continue;
+            }
+            if candidate.opcode == bytecode::SSTORE && !success_checked {
+                findings.push(
+                    Finding::new(
+                        Severity::Critical,
+                        "Unchecked external call before state write",
+                        format!(
+                            "CALL at pc {} is followed by an SSTORE at pc {} before its success \
+                             is checked",
+                            call.pc, candidate.pc
+                        ),
+                    )
+                    .with_swc_id("SWC-107")
+                    .with_location(CodeLocation::Bytecode { offset: call.pc }),
+                );
+                break;
+            }
+            if matches!(candidate.opcode, bytecode::ISZERO | bytecode::JUMPI) {
+                success_checked = true;
+            }
+        }
+    }
+    findings
+}
+
+#[async_trait]
+impl VulnerabilityScanner for ReentrancyScanner {
+    async fn scan(&self, address: &Address, bytecode: &[u8]) -> Result<Vec<Finding>> {
+        info!("Starting reentrancy scan for contract {}", address);
+        let findings = find_reentrancy_findings(bytecode);
+        debug!("Found {} potential reentrancy issues", findings.len());
+        Ok(findings)
+    }
+}
+
+/// Detects arithmetic (`ADD`/`MUL`/`SUB`) that isn't guarded by a preceding
+/// comparison (`LT`/`GT`/`ISZERO`) within the same basic block — Solidity's
+/// own checked-arithmetic codegen always inserts such a guard, so its
+/// absence is a reasonable signal of raw, unchecked assembly arithmetic
+pub struct IntegerOverflowScanner;
+
+fn find_integer_overflow_findings(bytecode_bytes: &[u8]) -> Vec<Finding> {
+    let instructions = bytecode::disassemble(bytecode_bytes);
+    let cfg = bytecode::ControlFlowGraph::build(&instructions);
+
+    let mut findings = Vec::new();
+    for block in &cfg.blocks {
+        let mut guarded = false;
+        for instruction in &block.instructions {
+            if matches!(instruction.opcode, bytecode::LT | bytecode::GT | bytecode::ISZERO) {
+                guarded = true;
+            }
+            if matches!(instruction.opcode, bytecode::ADD | bytecode::MUL | bytecode::SUB) && !guarded {
+                findings.push(
+                    Finding::new(
+                        Severity::Medium,
+                        "Unchecked arithmetic",
+                        format!(
+                            "{} at pc {} has no preceding LT/GT/ISZERO guard in its basic block",
+                            opcode_name(instruction.opcode),
+                            instruction.pc
+                        ),
+                    )
+                    .with_swc_id("SWC-101")
+                    .with_location(CodeLocation::Bytecode { offset: instruction.pc }),
+                );
+            }
+        }
+    }
+    findings
+}
+
+fn opcode_name(opcode: u8) -> &'static str {
+    match opcode {
+        bytecode::ADD => "ADD",
+        bytecode::MUL => "MUL",
+        bytecode::SUB => "SUB",
+        _ => "UNKNOWN",
+    }
+}
+
+#[async_trait]
+impl VulnerabilityScanner for IntegerOverflowScanner {
+    async fn scan(&self, address: &Address, bytecode: &[u8]) -> Result<Vec<Finding>> {
+        info!("Starting integer overflow scan for contract {}", address);
+        let findings = find_integer_overflow_findings(bytecode);
+        debug!("Found {} potential integer overflow issues", findings.len());
+        Ok(findings)
+    }
+}
+
+/// Detects `SELFDESTRUCT`/`DELEGATECALL` reachable without a preceding
+/// `CALLER` (`msg.sender`) check, and ones gated only by `ORIGIN`
+/// (`tx.origin`)
+///
+/// Like `ReentrancyScanner`/`IntegerOverflowScanner`, this is a static
+/// approximation over the control-flow graph: it checks whether `CALLER`
+/// or `ORIGIN` appears anywhere on a reachable path before the sensitive
+/// opcode, not whether the value they push is actually compared against an
+/// authorized address and used to gate execution. It flags the absence of
+/// the pattern real access-control code always contains, not a proof of
+/// exploitability.
+fn find_access_control_findings(bytecode_bytes: &[u8]) -> Vec<Finding> {
+    let instructions = bytecode::disassemble(bytecode_bytes);
+    let cfg = bytecode::ControlFlowGraph::build(&instructions);
+
+    let mut findings = Vec::new();
+    for sensitive in instructions
+        .iter()
+        .filter(|i| matches!(i.opcode, bytecode::SELFDESTRUCT | bytecode::DELEGATECALL))
+    {
+        let sensitive_name = match sensitive.opcode {
+            bytecode::SELFDESTRUCT => "SELFDESTRUCT",
+            bytecode::DELEGATECALL => "DELEGATECALL",
+            _ => unreachable!("filtered to SELFDESTRUCT/DELEGATECALL above"),
+        };
+
+        let mut caller_checked = false;
+        let mut origin_checked = false;
+        for candidate in instructions.iter().filter(|i| i.pc < sensitive.pc) {
+            if !cfg.is_reachable(candidate.pc, sensitive.pc) {
+                continue;
+            }
+            match candidate.opcode {
+                bytecode::CALLER => caller_checked = true,
+                bytecode::ORIGIN => origin_checked = true,
+                _ => {}
+            }
+        }
+
+        if !caller_checked && !origin_checked {
+            findings.push(
+                Finding::new(
+                    Severity::High,
+                    "Missing access control",
+                    format!(
+                        "{sensitive_name} at pc {} is reachable with no preceding CALLER (msg.sender) check on that path",
+                        sensitive.pc
+                    ),
+                )
+                .with_swc_id("SWC-105")
+                .with_location(CodeLocation::Bytecode { offset: sensitive.pc }),
+            );
+        } else if !caller_checked {
+            findings.push(
+                Finding::new(
+                    Severity::Medium,
+                    "Authorization through tx.origin",
+                    format!(
+                        "A path reaching {sensitive_name} at pc {} relies on ORIGIN (tx.origin) \
+                         for its access check, which is vulnerable to phishing through an \
+                         approved intermediary contract",
+                        sensitive.pc
+                    ),
+                )
+                .with_swc_id("SWC-115")
+                .with_location(CodeLocation::Bytecode { offset: sensitive.pc }),
+            );
+        }
+    }
+    findings
+}
+
+/// Scanner for detecting access control vulnerabilities
+pub struct AccessControlScanner;
+
+#[async_trait]
+impl VulnerabilityScanner for AccessControlScanner {
+    async fn scan(&self, address: &Address, bytecode: &[u8]) -> Result<Vec<Finding>> {
+        info!("Starting access control scan for contract {}", address);
+        let findings = find_access_control_findings(bytecode);
+        debug!("Found {} potential access control issues", findings.len());
+        Ok(findings)
+    }
+}
+
+/// Scanner driven by a `RuleRegistry` of known-vulnerability signatures
+///
+/// Unlike the fixed scanners above, `RuleBasedScanner` works directly
+/// against a `Transaction` (and optional contract bytecode) and returns
+/// structured `RuleFinding`s via `scan_transaction`, so new exploit
+/// signatures can be added by implementing `VulnerabilityRule` and
+/// registering it, instead of rewriting the scanner.
+///
+/// # Examples
+///
+/// ```
+/// use security_analyzer::vulnerabilities::RuleBasedScanner;
+/// use blockchain_core::models::Transaction;
+/// use common::types::{Address, Hash};
+///
+/// let scanner = RuleBasedScanner::new();
+///
+/// let tx = Transaction::new(
+///     Hash("0x123".to_string()),
+///     Address("0xabc".to_string()),
+///     Some(Address("0xdef".to_string())),
+///     1000,
+///     50,
+///     21000,
+///     5,
+///     vec![0xde, 0xad, 0xbe, 0xef],
+/// );
+///
+/// let findings = scanner.scan_transaction(&tx, Some(&[0xf4]));
+/// assert_eq!(findings[0].rule_id, "unprotected-delegatecall");
+/// ```
+pub struct RuleBasedScanner {
+    registry: RuleRegistry,
+}
+
+impl RuleBasedScanner {
+    /// Creates a scanner pre-loaded with the built-in rule set
+    pub fn new() -> Self {
+        info!("Initializing RuleBasedScanner with built-in rules");
+        Self {
+            registry: RuleRegistry::with_rules(built_in_rules()),
+        }
+    }
+
+    /// Creates a scanner loaded with exactly `rules`, no built-ins
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use security_analyzer::vulnerabilities::RuleBasedScanner;
+    /// use security_analyzer::rules::BlacklistedAddressRule;
+    ///
+    /// let scanner = RuleBasedScanner::with_rules(vec![
+    ///     Box::new(BlacklistedAddressRule::new(vec!["0xdef".to_string()])),
+    /// ]);
+    /// ```
+    pub fn with_rules(rules: Vec<Box<dyn VulnerabilityRule>>) -> Self {
+        info!("Initializing RuleBasedScanner with {} custom rule(s)", rules.len());
+        Self {
+            registry: RuleRegistry::with_rules(rules),
+        }
+    }
+
+    /// Registers an additional rule
+    pub fn register_rule(&mut self, rule: Box<dyn VulnerabilityRule>) {
+        self.registry.register(rule);
+    }
+
+    /// Evaluates every loaded rule against `tx`/`bytecode`, returning a
+    /// typed finding for each rule that matches
+    pub fn scan_transaction(&self, tx: &Transaction, bytecode: Option<&[u8]>) -> Vec<RuleFinding> {
+        debug!(
+            "Evaluating {} rule(s) against transaction {}",
+            self.registry.len(),
+            tx.hash
+        );
+        self.registry.evaluate(tx, bytecode)
+    }
+}
+
+#[async_trait]
+impl VulnerabilityScanner for RuleBasedScanner {
+    async fn scan(&self, address: &Address, bytecode: &[u8]) -> Result<Vec<Finding>> {
+        // The rules only inspect a `Transaction`'s `to`/`data`, not the
+        // address being scanned directly, so build a placeholder
+        // transaction addressed to the target contract to evaluate rules
+        // against its real bytecode.
+        debug!("Evaluating {} rule(s) against contract {}", self.registry.len(), address);
+        // Rules like `UnprotectedDelegatecallRule` key off `tx.data`, which
+        // has no real analogue for a pure bytecode scan; a non-empty
+        // placeholder keeps those rules evaluable without a real calling
+        // transaction.
+        let placeholder_tx = Transaction::new(
+            common::types::Hash(format!("0x{:0>64}", "0")),
+            address.clone(),
+            Some(address.clone()),
+            0,
+            0,
+            0,
+            0,
+            vec![0u8; 4],
+        );
+        let rule_findings = self.registry.evaluate(&placeholder_tx, Some(bytecode));
+        Ok(rule_findings.into_iter().map(Finding::from).collect())
+    }
+}
+
+impl Default for RuleBasedScanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Flags a WASM contract's host imports and memories for non-determinism
+/// and unbounded resource use
+///
+/// Any function import not on the configured allowlist is flagged High, on
+/// the theory that an unreviewed host call is guilty until proven innocent.
+/// Floating-point operands and memories (imported or module-defined) with
+/// no declared maximum are flagged Medium: each is a real risk (float
+/// results aren't guaranteed bit-identical across WASM runtimes; unbounded
+/// memory growth has no built-in ceiling), but neither is automatically
+/// exploitable the way an arbitrary host call is.
+pub struct WasmImportScanner {
+    allowlist: HashSet<(String, String)>,
+}
+
+impl WasmImportScanner {
+    /// Creates a scanner using `allowlist` as the set of `(module, name)`
+    /// host imports considered safe to call
+    pub fn new(allowlist: impl IntoIterator<Item = (String, String)>) -> Self {
+        Self {
+            allowlist: allowlist.into_iter().collect(),
+        }
+    }
+
+    /// Creates a scanner using a conservative built-in allowlist: nothing
+    /// beyond the bare memory import every WASM contract needs
+    pub fn with_default_allowlist() -> Self {
+        Self::new([("env".to_string(), "memory".to_string())])
+    }
+}
+
+impl Default for WasmImportScanner {
+    fn default() -> Self {
+        Self::with_default_allowlist()
+    }
+}
+
+#[async_trait]
+impl VulnerabilityScanner for WasmImportScanner {
+    async fn scan(&self, address: &Address, bytecode: &[u8]) -> Result<Vec<Finding>> {
+        info!("Starting WASM import scan for contract {}", address);
+        let module =
+            wasm::parse(bytecode).map_err(|e| common::error::Error::Validation(e.to_string()))?;
+
+        let mut findings = Vec::new();
+
+        for import in &module.imports {
+            match &import.kind {
+                wasm::ImportKind::Func { type_index } => {
+                    if !self.allowlist.contains(&(import.module.clone(), import.name.clone())) {
+                        findings.push(Finding::new(
+                            Severity::High,
+                            "Unallowlisted host import",
+                            format!(
+                                "Imports \"{}.{}\", which is not on the configured safe-import allowlist",
+                                import.module, import.name
+                            ),
+                        ));
+                    }
+                    if module.func_type(*type_index).is_some_and(wasm::FuncType::uses_floats) {
+                        findings.push(Finding::new(
+                            Severity::Medium,
+                            "Floating-point host import",
+                            format!(
+                                "Imported function \"{}.{}\" uses floating-point operands, which \
+                                 are not guaranteed to be deterministic across WASM runtimes",
+                                import.module, import.name
+                            ),
+                        ));
+                    }
+                }
+                wasm::ImportKind::Memory(limits) if limits.max.is_none() => {
+                    findings.push(Finding::new(
+                        Severity::Medium,
+                        "Unbounded imported memory",
+                        format!(
+                            "Imported memory \"{}.{}\" has no declared maximum size, allowing unbounded growth",
+                            import.module, import.name
+                        ),
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        for (index, memory) in module.memories.iter().enumerate() {
+            if memory.max.is_none() {
+                findings.push(Finding::new(
+                    Severity::Medium,
+                    "Unbounded memory growth",
+                    format!("Module-defined memory {} has no declared maximum size, allowing unbounded growth", index),
+                ));
+            }
+        }
+
+        debug!("Found {} WASM import issue(s)", findings.len());
+        Ok(findings)
+    }
+}
+
+/// Flags a WASM contract that declares no recognizable gas/fuel metering
+/// hook among its imports or exports
+///
+/// Without a host-provided metering import (or a self-metering export the
+/// runtime can call into), nothing bounds how much computation the module
+/// can demand once invoked.
+pub struct WasmResourceScanner;
+
+const METERING_HOOK_NAMES: &[&str] = &["gas", "fuel", "use_gas", "charge_gas", "__gas"];
+
+impl WasmResourceScanner {
+    fn is_metering_name(name: &str) -> bool {
+        METERING_HOOK_NAMES.iter().any(|hook| name.eq_ignore_ascii_case(hook))
+    }
+}
+
+#[async_trait]
+impl VulnerabilityScanner for WasmResourceScanner {
+    async fn scan(&self, address: &Address, bytecode: &[u8]) -> Result<Vec<Finding>> {
+        info!("Starting WASM resource metering scan for contract {}", address);
+        let module =
+            wasm::parse(bytecode).map_err(|e| common::error::Error::Validation(e.to_string()))?;
+
+        let has_metering_hook = module.imports.iter().any(|import| Self::is_metering_name(&import.name))
+            || module.exports.iter().any(|export| Self::is_metering_name(&export.name));
+
+        let findings = if has_metering_hook {
+            Vec::new()
+        } else {
+            vec![Finding::new(
+                Severity::High,
+                "No gas/fuel metering hook detected",
+                "Module declares no import or export matching a recognized gas/fuel metering \
+                 hook name, so execution cost cannot be bounded at runtime",
+            )]
+        };
+
+        debug!("WASM resource scan found {} issue(s)", findings.len());
+        Ok(findings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finding_to_report_string_with_swc_id() {
+        let finding = Finding::new(Severity::High, "Missing access control", "no guard found")
+            .with_swc_id("SWC-105");
+        assert_eq!(
+            finding.to_report_string(),
+            "High: Missing access control (SWC-105) - no guard found"
+        );
+    }
+
+    #[test]
+    fn test_finding_to_report_string_without_swc_id() {
+        let finding = Finding::new(Severity::Low, "Style nit", "consider renaming");
+        assert_eq!(finding.to_report_string(), "Low: Style nit - consider renaming");
+    }
+
+    #[test]
+    fn test_severity_to_risk_level_collapses_info_into_none() {
+        assert_eq!(RiskLevel::from(Severity::Info), RiskLevel::None);
+        assert_eq!(RiskLevel::from(Severity::Critical), RiskLevel::Critical);
+    }
+
+    #[tokio::test]
+    async fn test_reentrancy_scanner_flags_sstore_before_success_check() {
+        // CALL, SSTORE, STOP — state write with no preceding success check
+        let code = vec![bytecode::CALL, bytecode::SSTORE, bytecode::STOP];
+        let scanner = ReentrancyScanner;
+        let address = Address("0x123".to_string());
+
+        let findings = scanner.scan(&address, &code).await.unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Critical);
+        assert_eq!(findings[0].swc_id.as_deref(), Some("SWC-107"));
+    }
+
+    #[tokio::test]
+    async fn test_reentrancy_scanner_ignores_sstore_after_success_check() {
+        // CALL, ISZERO, SSTORE, STOP — success checked before the state write
+        let code = vec![bytecode::CALL, bytecode::ISZERO, bytecode::SSTORE, bytecode::STOP];
+        let scanner = ReentrancyScanner;
+        let address = Address("0x123".to_string());
+
+        let findings = scanner.scan(&address, &code).await.unwrap();
+        assert!(findings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_integer_overflow_scanner_flags_unguarded_arithmetic() {
+        // ADD with no preceding LT/GT/ISZERO in the block
+        let code = vec![bytecode::ADD, bytecode::STOP];
+        let scanner = IntegerOverflowScanner;
+        let address = Address("0x123".to_string());
+
+        let findings = scanner.scan(&address, &code).await.unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].swc_id.as_deref(), Some("SWC-101"));
+    }
+
+    #[tokio::test]
+    async fn test_integer_overflow_scanner_ignores_guarded_arithmetic() {
+        // LT, ADD — the LT guard precedes the arithmetic in the same block
+        let code = vec![bytecode::LT, bytecode::ADD, bytecode::STOP];
+        let scanner = IntegerOverflowScanner;
+        let address = Address("0x123".to_string());
+
+        let findings = scanner.scan(&address, &code).await.unwrap();
+        assert!(findings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_access_control_scanner_flags_unguarded_selfdestruct() {
+        // SELFDESTRUCT with no preceding CALLER check anywhere
+        let code = vec![bytecode::SELFDESTRUCT];
+        let scanner = AccessControlScanner;
+        let address = Address("0x123".to_string());
+
+        let findings = scanner.scan(&address, &code).await.unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::High);
+        assert_eq!(findings[0].swc_id.as_deref(), Some("SWC-105"));
+    }
+
+    #[tokio::test]
+    async fn test_access_control_scanner_ignores_caller_guarded_selfdestruct() {
+        // CALLER, EQ, SELFDESTRUCT — a msg.sender check precedes the sensitive call
+        let code = vec![bytecode::CALLER, bytecode::EQ, bytecode::SELFDESTRUCT];
+        let scanner = AccessControlScanner;
+        let address = Address("0x123".to_string());
+
+        let findings = scanner.scan(&address, &code).await.unwrap();
+        assert!(findings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_access_control_scanner_flags_tx_origin_check() {
+        // ORIGIN, EQ, DELEGATECALL — gated only by tx.origin, not msg.sender
+        let code = vec![bytecode::ORIGIN, bytecode::EQ, bytecode::DELEGATECALL];
+        let scanner = AccessControlScanner;
+        let address = Address("0x123".to_string());
+
+        let findings = scanner.scan(&address, &code).await.unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Medium);
+        assert_eq!(findings[0].swc_id.as_deref(), Some("SWC-115"));
+    }
+
+    #[tokio::test]
+    async fn test_rule_based_scanner_scan_transaction() {
+        use common::types::Hash;
+
+        let scanner = RuleBasedScanner::new();
+        let tx = Transaction::new(
+            Hash("0x123".to_string()),
+            Address("0xabc".to_string()),
+            Some(Address("0xdef".to_string())),
+            1000,
+            50,
+            21000,
+            5,
+            vec![0xde, 0xad, 0xbe, 0xef],
+        );
+
+        let findings = scanner.scan_transaction(&tx, Some(&[0xf4]));
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "unprotected-delegatecall");
+    }
+
+    #[tokio::test]
+    async fn test_rule_based_scanner_scan_evaluates_real_bytecode() {
+        let scanner = RuleBasedScanner::new();
+        let address = Address("0x123".to_string());
+
+        let findings = scanner.scan(&address, &[0xf4]).await.unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].swc_id, None);
+        assert_eq!(findings[0].title, "unprotected-delegatecall");
+        assert_eq!(findings[0].severity, Severity::Critical);
+    }
+
+    #[tokio::test]
+    async fn test_rule_based_scanner_scan_with_no_matches_is_empty() {
+        let scanner = RuleBasedScanner::new();
+        let address = Address("0x123".to_string());
+
+        let findings = scanner.scan(&address, &[]).await.unwrap();
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn test_contract_kind_detects_wasm_magic() {
+        assert_eq!(
+            ContractKind::detect(&[0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00]),
+            ContractKind::Wasm
+        );
+        assert_eq!(ContractKind::detect(&[0x60, 0x60, 0x60, 0x40]), ContractKind::Evm);
+        assert_eq!(ContractKind::detect(&[]), ContractKind::Evm);
+    }
+
+    fn leb(value: u32) -> Vec<u8> {
+        let mut out = Vec::new();
+        let mut value = value;
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                out.push(byte);
+                break;
+            }
+            out.push(byte | 0x80);
+        }
+        out
+    }
+
+    fn wasm_section(id: u8, content: Vec<u8>) -> Vec<u8> {
+        let mut out = vec![id];
+        out.extend(leb(content.len() as u32));
+        out.extend(content);
+        out
+    }
+
+    fn wasm_module_with_import(import_module: &str, import_name: &str, type_index: u32, float_signature: bool) -> Vec<u8> {
+        let functype = if float_signature {
+            vec![0x60, 0x01, 0x7c, 0x00] // (f64) -> ()
+        } else {
+            vec![0x60, 0x00, 0x00] // () -> ()
+        };
+        let mut type_content = leb(1);
+        type_content.extend(functype);
+
+        let mut import_content = leb(1);
+        import_content.extend(leb(import_module.len() as u32));
+        import_content.extend(import_module.as_bytes());
+        import_content.extend(leb(import_name.len() as u32));
+        import_content.extend(import_name.as_bytes());
+        import_content.push(0x00);
+        import_content.extend(leb(type_index));
+
+        let mut out = vec![0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+        out.extend(wasm_section(1, type_content));
+        out.extend(wasm_section(2, import_content));
+        out
+    }
+
+    #[tokio::test]
+    async fn test_wasm_import_scanner_flags_unallowlisted_import() {
+        let module = wasm_module_with_import("env", "dangerous_call", 0, false);
+        let scanner = WasmImportScanner::with_default_allowlist();
+        let address = Address("0xwasm".to_string());
+
+        let findings = scanner.scan(&address, &module).await.unwrap();
+        assert!(findings.iter().any(|f| f.title == "Unallowlisted host import"));
+    }
+
+    #[tokio::test]
+    async fn test_wasm_import_scanner_allows_allowlisted_import() {
+        let module = wasm_module_with_import("env", "memory", 0, false);
+        let scanner = WasmImportScanner::new([("env".to_string(), "memory".to_string())]);
+        let address = Address("0xwasm".to_string());
+
+        let findings = scanner.scan(&address, &module).await.unwrap();
+        assert!(findings.iter().all(|f| f.title != "Unallowlisted host import"));
+    }
+
+    #[tokio::test]
+    async fn test_wasm_import_scanner_flags_floating_point_import() {
+        let module = wasm_module_with_import("env", "memory", 0, true);
+        let scanner = WasmImportScanner::new([("env".to_string(), "memory".to_string())]);
+        let address = Address("0xwasm".to_string());
+
+        let findings = scanner.scan(&address, &module).await.unwrap();
+        assert!(findings.iter().any(|f| f.title == "Floating-point host import"));
+    }
+
+    #[tokio::test]
+    async fn test_wasm_resource_scanner_flags_missing_metering_hook() {
+        let module = wasm_module_with_import("env", "memory", 0, false);
+        let scanner = WasmResourceScanner;
+        let address = Address("0xwasm".to_string());
+
+        let findings = scanner.scan(&address, &module).await.unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].title, "No gas/fuel metering hook detected");
+    }
+
+    #[tokio::test]
+    async fn test_wasm_resource_scanner_accepts_present_metering_hook() {
+        let module = wasm_module_with_import("env", "gas", 0, false);
+        let scanner = WasmResourceScanner;
+        let address = Address("0xwasm".to_string());
+
+        let findings = scanner.scan(&address, &module).await.unwrap();
+        assert!(findings.is_empty());
+    }
+}
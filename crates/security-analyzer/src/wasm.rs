@@ -0,0 +1,460 @@
+// security-analyzer/src/wasm.rs
+//! Minimal WebAssembly binary-format parsing
+//!
+//! Mirrors `bytecode.rs`'s approach for EVM: just enough structural
+//! decoding (types, imports, functions, memories, exports) for the WASM
+//! scanners to make static judgments from, not a full WASM validator or
+//! interpreter — function bodies (the code section) are skipped entirely.
+
+use std::fmt;
+
+/// The four magic bytes every WASM binary module starts with
+pub const WASM_MAGIC: [u8; 4] = [0x00, 0x61, 0x73, 0x6d];
+const WASM_VERSION: [u8; 4] = [0x01, 0x00, 0x00, 0x00];
+
+const SECTION_TYPE: u8 = 1;
+const SECTION_IMPORT: u8 = 2;
+const SECTION_FUNCTION: u8 = 3;
+const SECTION_MEMORY: u8 = 5;
+const SECTION_EXPORT: u8 = 7;
+
+/// WASM value types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValType {
+    I32,
+    I64,
+    F32,
+    F64,
+    /// Any value type byte this parser doesn't special-case (vectors,
+    /// reference types, ...)
+    Other(u8),
+}
+
+impl ValType {
+    fn from_byte(byte: u8) -> Self {
+        match byte {
+            0x7f => ValType::I32,
+            0x7e => ValType::I64,
+            0x7d => ValType::F32,
+            0x7c => ValType::F64,
+            other => ValType::Other(other),
+        }
+    }
+
+    /// Whether this is one of the floating-point value types
+    pub fn is_float(self) -> bool {
+        matches!(self, ValType::F32 | ValType::F64)
+    }
+}
+
+/// A function signature, as declared in the type section
+#[derive(Debug, Clone, Default)]
+pub struct FuncType {
+    pub params: Vec<ValType>,
+    pub results: Vec<ValType>,
+}
+
+impl FuncType {
+    /// Whether any parameter or result type is floating-point
+    pub fn uses_floats(&self) -> bool {
+        self.params.iter().chain(&self.results).any(|t| t.is_float())
+    }
+}
+
+/// A memory's size limits, in WASM pages (64 KiB each)
+#[derive(Debug, Clone, Copy)]
+pub struct MemoryLimits {
+    pub min: u32,
+    pub max: Option<u32>,
+}
+
+/// The kind-specific payload of a single import
+#[derive(Debug, Clone)]
+pub enum ImportKind {
+    Func { type_index: u32 },
+    Table,
+    Memory(MemoryLimits),
+    Global,
+}
+
+/// A single host import declared in the import section
+#[derive(Debug, Clone)]
+pub struct Import {
+    pub module: String,
+    pub name: String,
+    pub kind: ImportKind,
+}
+
+/// The kind of item a single export refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportKind {
+    Func,
+    Table,
+    Memory,
+    Global,
+}
+
+/// A single export declared in the export section
+#[derive(Debug, Clone)]
+pub struct Export {
+    pub name: String,
+    pub kind: ExportKind,
+    pub index: u32,
+}
+
+/// The subset of a parsed WASM module's structure the scanners need
+#[derive(Debug, Clone, Default)]
+pub struct WasmModule {
+    pub types: Vec<FuncType>,
+    pub imports: Vec<Import>,
+    /// Type-section index of each module-defined (non-imported) function
+    pub functions: Vec<u32>,
+    /// Module-defined memories (memory-section entries only; imported
+    /// memories are reachable via `imports` instead)
+    pub memories: Vec<MemoryLimits>,
+    pub exports: Vec<Export>,
+}
+
+impl WasmModule {
+    /// Resolves a type-section index to its `FuncType`, if in range
+    pub fn func_type(&self, type_index: u32) -> Option<&FuncType> {
+        self.types.get(type_index as usize)
+    }
+}
+
+/// Error parsing a WASM module
+///
+/// `parse` only walks section framing (and the handful of section payloads
+/// listed above); every variant here reflects truncated or malformed input
+/// in that framing, not a semantic validation failure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError(pub String);
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid WASM module: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn remaining(&self) -> usize {
+        self.bytes.len() - self.pos
+    }
+
+    fn byte(&mut self) -> Result<u8, ParseError> {
+        let b = *self
+            .bytes
+            .get(self.pos)
+            .ok_or_else(|| ParseError("unexpected end of input".to_string()))?;
+        self.pos += 1;
+        Ok(b)
+    }
+
+    fn bytes_exact(&mut self, n: usize) -> Result<&'a [u8], ParseError> {
+        if self.remaining() < n {
+            return Err(ParseError("unexpected end of input".to_string()));
+        }
+        let slice = &self.bytes[self.pos..self.pos + n];
+        self.pos += n;
+        Ok(slice)
+    }
+
+    /// Reads an unsigned LEB128 varint
+    fn u32_leb128(&mut self) -> Result<u32, ParseError> {
+        let mut result: u32 = 0;
+        let mut shift = 0u32;
+        loop {
+            let byte = self.byte()?;
+            result |= u32::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                break;
+            }
+            shift += 7;
+            if shift >= 35 {
+                return Err(ParseError("LEB128 varint too long".to_string()));
+            }
+        }
+        Ok(result)
+    }
+
+    fn name(&mut self) -> Result<String, ParseError> {
+        let len = self.u32_leb128()? as usize;
+        let bytes = self.bytes_exact(len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|e| ParseError(format!("invalid utf8 name: {}", e)))
+    }
+
+    fn limits(&mut self) -> Result<MemoryLimits, ParseError> {
+        let flags = self.byte()?;
+        let min = self.u32_leb128()?;
+        let max = if flags & 0x01 != 0 {
+            Some(self.u32_leb128()?)
+        } else {
+            None
+        };
+        Ok(MemoryLimits { min, max })
+    }
+}
+
+/// Parses a WASM binary module into its types/imports/functions/memories/exports
+///
+/// Only section framing and the listed section payloads are decoded;
+/// function bodies (the code section) and other sections are skipped over
+/// using their own declared length, since the static scanners built on top
+/// of this don't need them.
+///
+/// # Examples
+///
+/// ```
+/// use security_analyzer::wasm::parse;
+///
+/// // Magic + version, no sections: the minimal valid empty module
+/// let module = parse(&[0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00]).unwrap();
+/// assert!(module.imports.is_empty());
+/// ```
+pub fn parse(bytecode: &[u8]) -> Result<WasmModule, ParseError> {
+    let mut reader = Reader::new(bytecode);
+
+    if reader.bytes_exact(4)? != WASM_MAGIC {
+        return Err(ParseError("missing WASM magic bytes".to_string()));
+    }
+    if reader.bytes_exact(4)? != WASM_VERSION {
+        return Err(ParseError("unsupported WASM version".to_string()));
+    }
+
+    let mut module = WasmModule::default();
+
+    while reader.remaining() > 0 {
+        let section_id = reader.byte()?;
+        let section_len = reader.u32_leb128()? as usize;
+        let section_bytes = reader.bytes_exact(section_len)?;
+        let mut section = Reader::new(section_bytes);
+
+        match section_id {
+            SECTION_TYPE => {
+                let count = section.u32_leb128()?;
+                for _ in 0..count {
+                    let form = section.byte()?;
+                    if form != 0x60 {
+                        return Err(ParseError(format!("unsupported type form {:#x}", form)));
+                    }
+                    let param_count = section.u32_leb128()?;
+                    let params = (0..param_count)
+                        .map(|_| section.byte().map(ValType::from_byte))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    let result_count = section.u32_leb128()?;
+                    let results = (0..result_count)
+                        .map(|_| section.byte().map(ValType::from_byte))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    module.types.push(FuncType { params, results });
+                }
+            }
+            SECTION_IMPORT => {
+                let count = section.u32_leb128()?;
+                for _ in 0..count {
+                    let import_module = section.name()?;
+                    let import_name = section.name()?;
+                    let kind_byte = section.byte()?;
+                    let kind = match kind_byte {
+                        0x00 => ImportKind::Func {
+                            type_index: section.u32_leb128()?,
+                        },
+                        0x01 => {
+                            // tabletype: elemtype byte + limits
+                            section.byte()?;
+                            section.limits()?;
+                            ImportKind::Table
+                        }
+                        0x02 => ImportKind::Memory(section.limits()?),
+                        0x03 => {
+                            // globaltype: valtype byte + mutability byte
+                            section.byte()?;
+                            section.byte()?;
+                            ImportKind::Global
+                        }
+                        other => return Err(ParseError(format!("unsupported import kind {:#x}", other))),
+                    };
+                    module.imports.push(Import {
+                        module: import_module,
+                        name: import_name,
+                        kind,
+                    });
+                }
+            }
+            SECTION_FUNCTION => {
+                let count = section.u32_leb128()?;
+                for _ in 0..count {
+                    module.functions.push(section.u32_leb128()?);
+                }
+            }
+            SECTION_MEMORY => {
+                let count = section.u32_leb128()?;
+                for _ in 0..count {
+                    module.memories.push(section.limits()?);
+                }
+            }
+            SECTION_EXPORT => {
+                let count = section.u32_leb128()?;
+                for _ in 0..count {
+                    let name = section.name()?;
+                    let kind_byte = section.byte()?;
+                    let kind = match kind_byte {
+                        0x00 => ExportKind::Func,
+                        0x01 => ExportKind::Table,
+                        0x02 => ExportKind::Memory,
+                        0x03 => ExportKind::Global,
+                        other => return Err(ParseError(format!("unsupported export kind {:#x}", other))),
+                    };
+                    let index = section.u32_leb128()?;
+                    module.exports.push(Export { name, kind, index });
+                }
+            }
+            _ => {
+                // Other sections (code, data, custom, ...) don't matter to
+                // the static checks built on top of this parser.
+            }
+        }
+    }
+
+    Ok(module)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leb(value: u32) -> Vec<u8> {
+        let mut out = Vec::new();
+        let mut value = value;
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                out.push(byte);
+                break;
+            }
+            out.push(byte | 0x80);
+        }
+        out
+    }
+
+    fn section(id: u8, content: Vec<u8>) -> Vec<u8> {
+        let mut out = vec![id];
+        out.extend(leb(content.len() as u32));
+        out.extend(content);
+        out
+    }
+
+    fn module_bytes(sections: Vec<Vec<u8>>) -> Vec<u8> {
+        let mut out = WASM_MAGIC.to_vec();
+        out.extend(WASM_VERSION);
+        for s in sections {
+            out.extend(s);
+        }
+        out
+    }
+
+    #[test]
+    fn test_parse_rejects_missing_magic() {
+        assert!(parse(&[0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00]).is_err());
+    }
+
+    #[test]
+    fn test_parse_empty_module() {
+        let module = parse(&module_bytes(vec![])).unwrap();
+        assert!(module.types.is_empty());
+        assert!(module.imports.is_empty());
+    }
+
+    #[test]
+    fn test_parse_type_section_decodes_float_signature() {
+        // one functype: (f64) -> (i32)
+        let functype = {
+            let mut bytes = vec![0x60];
+            bytes.extend(leb(1));
+            bytes.push(0x7c); // f64 param
+            bytes.extend(leb(1));
+            bytes.push(0x7f); // i32 result
+            bytes
+        };
+        let mut type_section_content = leb(1);
+        type_section_content.extend(functype);
+
+        let module = parse(&module_bytes(vec![section(SECTION_TYPE, type_section_content)])).unwrap();
+        assert_eq!(module.types.len(), 1);
+        assert!(module.types[0].uses_floats());
+    }
+
+    #[test]
+    fn test_parse_import_section_decodes_func_and_memory() {
+        let mut content = leb(2); // two imports
+
+        // import 0: "env"."dangerous_call" func, type index 0
+        content.extend(leb(3));
+        content.extend(b"env");
+        content.extend(leb(14));
+        content.extend(b"dangerous_call");
+        content.push(0x00);
+        content.extend(leb(0));
+
+        // import 1: "env"."memory" memory, min 1 page, no max
+        content.extend(leb(3));
+        content.extend(b"env");
+        content.extend(leb(6));
+        content.extend(b"memory");
+        content.push(0x02);
+        content.push(0x00); // flags: no max
+        content.extend(leb(1));
+
+        let module = parse(&module_bytes(vec![section(SECTION_IMPORT, content)])).unwrap();
+        assert_eq!(module.imports.len(), 2);
+        assert_eq!(module.imports[0].module, "env");
+        assert_eq!(module.imports[0].name, "dangerous_call");
+        assert!(matches!(module.imports[0].kind, ImportKind::Func { type_index: 0 }));
+        match &module.imports[1].kind {
+            ImportKind::Memory(limits) => {
+                assert_eq!(limits.min, 1);
+                assert!(limits.max.is_none());
+            }
+            other => panic!("expected a memory import, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_memory_and_export_sections() {
+        let mut memory_content = leb(1);
+        memory_content.push(0x01); // flags: has max
+        memory_content.extend(leb(2));
+        memory_content.extend(leb(10));
+
+        let mut export_content = leb(1);
+        export_content.extend(leb(4));
+        export_content.extend(b"main");
+        export_content.push(0x00);
+        export_content.extend(leb(0));
+
+        let module = parse(&module_bytes(vec![
+            section(SECTION_MEMORY, memory_content),
+            section(SECTION_EXPORT, export_content),
+        ]))
+        .unwrap();
+
+        assert_eq!(module.memories.len(), 1);
+        assert_eq!(module.memories[0].min, 2);
+        assert_eq!(module.memories[0].max, Some(10));
+
+        assert_eq!(module.exports.len(), 1);
+        assert_eq!(module.exports[0].name, "main");
+        assert_eq!(module.exports[0].kind, ExportKind::Func);
+    }
+}
@@ -0,0 +1,96 @@
+// transaction-analyzer/src/findings.rs
+use common::types::RiskLevel;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A single structured finding produced by a `TransactionAnalyzer`
+///
+/// Unlike the ad-hoc `HashMap<String, String>` returned by
+/// `analyze_transaction`, a `Finding` carries a real `RiskLevel` so callers
+/// can filter and alert by severity instead of parsing string values.
+///
+/// # Examples
+///
+/// ```
+/// use transaction_analyzer::findings::Finding;
+/// use common::types::RiskLevel;
+///
+/// let finding = Finding {
+///     id: "signature_recovery".to_string(),
+///     title: "ECDSA signature verification".to_string(),
+///     risk: RiskLevel::High,
+///     detail: "recovered sender does not match declared from".to_string(),
+///     location: None,
+/// };
+///
+/// assert_eq!(finding.risk, RiskLevel::High);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Finding {
+    /// Stable identifier for the kind of finding, e.g. `"signature_recovery"`
+    pub id: String,
+    /// Short human-readable title
+    pub title: String,
+    /// Severity of the finding
+    pub risk: RiskLevel,
+    /// Full explanation of what was found
+    pub detail: String,
+    /// Where the finding applies, e.g. a storage slot or program counter;
+    /// `None` when the finding is about the transaction as a whole
+    pub location: Option<String>,
+}
+
+/// Aggregated result of running all of a `TransactionProcessor`'s registered
+/// analyzers against a single transaction
+///
+/// # Examples
+///
+/// ```
+/// use transaction_analyzer::findings::{AnalysisReport, Finding};
+/// use common::types::RiskLevel;
+/// use std::collections::HashMap;
+///
+/// let report = AnalysisReport {
+///     risk: RiskLevel::Medium,
+///     findings: vec![Finding {
+///         id: "gas_griefing".to_string(),
+///         title: "Gas griefing step".to_string(),
+///         risk: RiskLevel::Medium,
+///         detail: "step consumed most of the remaining gas".to_string(),
+///         location: Some("pc=42".to_string()),
+///     }],
+///     combined: HashMap::new(),
+/// };
+///
+/// assert_eq!(report.risk, RiskLevel::Medium);
+/// assert_eq!(report.findings.len(), 1);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalysisReport {
+    /// Overall risk level — the maximum severity across `findings`, or
+    /// `RiskLevel::None` when there are none
+    pub risk: RiskLevel,
+    /// Structured findings from analyzers that implement `analyze`
+    pub findings: Vec<Finding>,
+    /// Legacy string-keyed results from `analyze_transaction`, kept
+    /// alongside `findings` so existing consumers don't lose data
+    pub combined: HashMap<String, String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finding_equality() {
+        let a = Finding {
+            id: "x".to_string(),
+            title: "X".to_string(),
+            risk: RiskLevel::Low,
+            detail: "detail".to_string(),
+            location: None,
+        };
+        let b = a.clone();
+        assert_eq!(a, b);
+    }
+}
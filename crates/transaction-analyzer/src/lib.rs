@@ -38,10 +38,14 @@
 
 pub mod processor;
 pub mod ml;
+pub mod signature;
+pub mod findings;
 
 // Re-export main types
-pub use processor::{TransactionAnalyzer, TransactionProcessor};
+pub use processor::{EvmTraceAnalyzer, TraceStep, TransactionAnalyzer, TransactionProcessor};
 pub use ml::MLTransactionAnalyzer;
+pub use signature::SignatureAnalyzer;
+pub use findings::{AnalysisReport, Finding};
 
 /// Initialize the transaction analyzer library
 ///
@@ -0,0 +1,820 @@
+// transaction-analyzer/src/processor.rs
+use async_trait::async_trait;
+use common::{
+    bloom::Bloom,
+    error::Result,
+    logging::create_timing_span,
+    types::RiskLevel,
+};
+use futures::stream::{self, StreamExt};
+use tracing::{debug, error, info, warn, instrument};
+use blockchain_core::models::Transaction;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::findings::{AnalysisReport, Finding};
+
+/// Default number of transactions processed concurrently by
+/// `TransactionProcessor::process_batch`
+const DEFAULT_BATCH_CONCURRENCY: usize = 8;
+
+/// A single step of a replayed EVM execution trace
+///
+/// Traces are produced by re-executing a transaction against a VM (or
+/// replaying a `debug_traceTransaction`-style log) and capturing the
+/// per-opcode machine state. Analyzers that implement `analyze_trace`
+/// receive the full step sequence instead of just the static `Transaction`
+/// fields, which lets them reason about control flow and storage access
+/// patterns that only exist at execution time.
+#[derive(Debug, Clone)]
+pub struct TraceStep {
+    /// Program counter of the executed instruction
+    pub pc: u64,
+    /// Mnemonic of the executed opcode, e.g. `"JUMPI"`, `"SSTORE"`, `"CALL"`
+    pub opcode: String,
+    /// Gas remaining before this step's cost is deducted
+    pub gas_remaining: u64,
+    /// Gas charged for this step
+    pub gas_cost: u64,
+    /// Depth of the EVM stack after executing this step
+    pub stack_depth: u32,
+    /// Storage slots (as hex strings) touched by this step — the slot read
+    /// for `SLOAD`/`SSTORE_READ`-style steps, or the slot written for
+    /// `SSTORE` steps. Empty for steps that don't touch storage.
+    pub storage_writes: Vec<String>,
+}
+
+/// Trait for transaction analysis strategies
+///
+/// This trait defines the interface for different transaction analysis
+/// strategies that can be plugged into the transaction processor.
+///
+/// # Examples
+///
+/// ```
+/// use transaction_analyzer::processor::TransactionAnalyzer;
+/// use blockchain_core::models::Transaction;
+/// use common::error::Result;
+/// use async_trait::async_trait;
+/// use std::collections::HashMap;
+///
+/// struct SimpleAnalyzer;
+///
+/// #[async_trait]
+/// impl TransactionAnalyzer for SimpleAnalyzer {
+///     async fn analyze_transaction(&self, tx: &Transaction) -> Result<HashMap<String, String>> {
+///         let mut results = HashMap::new();
+///         results.insert("status".to_string(), "analyzed".to_string());
+///         results.insert("gas_efficiency".to_string(), "good".to_string());
+///         Ok(results)
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait TransactionAnalyzer: Send + Sync {
+    /// Analyzes a single transaction and returns analysis results
+    ///
+    /// # Arguments
+    ///
+    /// * `tx` - The transaction to analyze
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing a HashMap of analysis results,
+    /// or an Error if the analysis fails.
+    async fn analyze_transaction(&self, tx: &Transaction) -> Result<HashMap<String, String>>;
+
+    /// Analyzes a transaction's execution trace
+    ///
+    /// Analyzers that only care about the static transaction fields don't
+    /// need to implement this — it delegates to `analyze_transaction` by
+    /// default. Analyzers that want instruction-level detail (e.g. the
+    /// EVM trace analyzer) should override it.
+    async fn analyze_trace(
+        &self,
+        tx: &Transaction,
+        _trace: &[TraceStep],
+    ) -> Result<HashMap<String, String>> {
+        self.analyze_transaction(tx).await
+    }
+
+    /// Analyzes a transaction and returns structured, severity-ranked findings
+    ///
+    /// Analyzers that only produce the legacy string-keyed results don't
+    /// need to implement this — it defaults to no findings, so their output
+    /// still flows into `AnalysisReport::combined` without contributing a
+    /// `RiskLevel`. Analyzers that can classify severity (e.g. the signature
+    /// analyzer) should override it.
+    async fn analyze(&self, _tx: &Transaction) -> Result<Vec<Finding>> {
+        Ok(Vec::new())
+    }
+}
+
+/// Main transaction processor that coordinates analysis strategies
+///
+/// The TransactionProcessor manages multiple analyzers and coordinates
+/// the analysis of blockchain transactions.
+pub struct TransactionProcessor {
+    analyzers: Vec<Arc<dyn TransactionAnalyzer>>,
+    /// Maximum number of transactions `process_batch` runs concurrently
+    concurrency: usize,
+}
+
+impl TransactionProcessor {
+    /// Creates a new TransactionProcessor instance
+    pub fn new() -> Self {
+        info!("Initializing TransactionProcessor");
+        Self {
+            analyzers: Vec::new(),
+            concurrency: DEFAULT_BATCH_CONCURRENCY,
+        }
+    }
+
+    /// Creates a new TransactionProcessor with a custom batch concurrency
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use transaction_analyzer::processor::TransactionProcessor;
+    ///
+    /// let processor = TransactionProcessor::with_concurrency(16);
+    /// ```
+    pub fn with_concurrency(concurrency: usize) -> Self {
+        info!("Initializing TransactionProcessor with concurrency {}", concurrency);
+        Self {
+            analyzers: Vec::new(),
+            concurrency: concurrency.max(1),
+        }
+    }
+
+    /// Registers a new transaction analyzer
+    pub fn register_analyzer(&mut self, analyzer: Arc<dyn TransactionAnalyzer>) {
+        debug!("Registering new transaction analyzer");
+        self.analyzers.push(analyzer);
+    }
+
+    /// Processes a single transaction through all registered analyzers
+    ///
+    /// # Arguments
+    ///
+    /// * `tx` - The transaction to process
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing a HashMap of analysis results from all analyzers,
+    /// or an Error if the processing fails.
+    #[instrument(skip(self, tx), fields(tx_hash = %tx.hash), level = "debug")]
+    pub async fn process_transaction(&self, tx: &Transaction) -> Result<HashMap<String, String>> {
+        let _timing_span = create_timing_span("transaction_analysis", "process");
+        info!("Processing transaction {}", tx.hash);
+
+        if self.analyzers.is_empty() {
+            warn!("No transaction analyzers registered");
+            let mut results = HashMap::new();
+            results.insert("status".to_string(), "No analyzers configured".to_string());
+            return Ok(results);
+        }
+
+        let mut combined_results = HashMap::new();
+
+        for (i, analyzer) in self.analyzers.iter().enumerate() {
+            match analyzer.analyze_transaction(tx).await {
+                Ok(results) => {
+                    debug!(
+                        "Analyzer {} completed successfully for transaction {}",
+                        i,
+                        tx.hash
+                    );
+                    combined_results.extend(results);
+                }
+                Err(e) => {
+                    error!("Analyzer {} failed for transaction {}: {}", i, tx.hash, e);
+                    combined_results.insert(
+                        format!("analyzer_{}_error", i),
+                        format!("Analysis failed: {}", e),
+                    );
+                }
+            }
+        }
+
+        debug!(
+            "Completed processing transaction {} with {} result fields",
+            tx.hash,
+            combined_results.len()
+        );
+
+        Ok(combined_results)
+    }
+
+    /// Replays a transaction's execution trace through all registered analyzers
+    ///
+    /// Behaves like `process_transaction`, but calls `analyze_trace` so
+    /// trace-aware analyzers (e.g. the EVM trace analyzer) get instruction-
+    /// level detail instead of just the static transaction fields.
+    #[instrument(skip(self, tx, trace), fields(tx_hash = %tx.hash), level = "debug")]
+    pub async fn process_trace(
+        &self,
+        tx: &Transaction,
+        trace: &[TraceStep],
+    ) -> Result<HashMap<String, String>> {
+        let _timing_span = create_timing_span("transaction_analysis", "process_trace");
+        info!("Replaying trace of {} steps for transaction {}", trace.len(), tx.hash);
+
+        if self.analyzers.is_empty() {
+            warn!("No transaction analyzers registered");
+            let mut results = HashMap::new();
+            results.insert("status".to_string(), "No analyzers configured".to_string());
+            return Ok(results);
+        }
+
+        let mut combined_results = HashMap::new();
+
+        for (i, analyzer) in self.analyzers.iter().enumerate() {
+            match analyzer.analyze_trace(tx, trace).await {
+                Ok(results) => {
+                    debug!(
+                        "Analyzer {} completed trace analysis for transaction {}",
+                        i,
+                        tx.hash
+                    );
+                    combined_results.extend(results);
+                }
+                Err(e) => {
+                    error!("Analyzer {} failed trace analysis for transaction {}: {}", i, tx.hash, e);
+                    combined_results.insert(
+                        format!("analyzer_{}_error", i),
+                        format!("Trace analysis failed: {}", e),
+                    );
+                }
+            }
+        }
+
+        Ok(combined_results)
+    }
+
+    /// Runs all registered analyzers against a transaction and aggregates
+    /// their structured findings into a single `AnalysisReport`
+    ///
+    /// The report's overall `risk` is the maximum `RiskLevel` across every
+    /// `Finding` returned by `analyze`, or `RiskLevel::None` when there are
+    /// none. `combined` still carries the legacy `analyze_transaction`
+    /// string map, so this doesn't drop data for consumers that haven't
+    /// migrated to `Finding` yet.
+    #[instrument(skip(self, tx), fields(tx_hash = %tx.hash), level = "debug")]
+    pub async fn analyze(&self, tx: &Transaction) -> Result<AnalysisReport> {
+        let _timing_span = create_timing_span("transaction_analysis", "analyze");
+        info!("Aggregating typed findings for transaction {}", tx.hash);
+
+        let combined = self.process_transaction(tx).await?;
+
+        let mut findings = Vec::new();
+        for (i, analyzer) in self.analyzers.iter().enumerate() {
+            match analyzer.analyze(tx).await {
+                Ok(mut analyzer_findings) => findings.append(&mut analyzer_findings),
+                Err(e) => {
+                    error!("Analyzer {} failed to produce findings for transaction {}: {}", i, tx.hash, e);
+                }
+            }
+        }
+
+        let risk = findings
+            .iter()
+            .map(|f| f.risk)
+            .max()
+            .unwrap_or(RiskLevel::None);
+
+        debug!(
+            "Analysis of transaction {} produced {} finding(s), overall risk {}",
+            tx.hash,
+            findings.len(),
+            risk
+        );
+
+        Ok(AnalysisReport {
+            risk,
+            findings,
+            combined,
+        })
+    }
+
+    /// Processes multiple transactions in batch
+    ///
+    /// # Arguments
+    ///
+    /// * `transactions` - A vector of transactions to process
+    /// * `filter` - An optional bloom filter; transactions whose `from`/`to`
+    ///   addresses don't match it are skipped without running any analyzer,
+    ///   which matters for large batches
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing a HashMap mapping transaction hashes to analysis results,
+    /// or an Error if the batch processing fails.
+    #[instrument(skip(self, transactions, filter), level = "debug")]
+    pub async fn process_batch(
+        &self,
+        transactions: &[Transaction],
+        filter: Option<&Bloom>,
+    ) -> Result<HashMap<String, HashMap<String, String>>> {
+        let _timing_span = create_timing_span("transaction_analysis", "batch_process");
+        info!("Processing batch of {} transactions", transactions.len());
+
+        let candidates: Vec<&Transaction> = transactions
+            .iter()
+            .filter(|tx| filter.map_or(true, |bloom| Self::matches_filter(tx, bloom)))
+            .collect();
+        let skipped = transactions.len() - candidates.len();
+
+        let results = stream::iter(candidates)
+            .map(|tx| async move {
+                let result = self.process_transaction(tx).await;
+                (tx.hash.to_string(), result)
+            })
+            .buffer_unordered(self.concurrency)
+            .collect::<Vec<_>>()
+            .await;
+
+        let mut batch_results = HashMap::new();
+        for (hash, result) in results {
+            match result {
+                Ok(tx_results) => {
+                    batch_results.insert(hash, tx_results);
+                }
+                Err(e) => {
+                    error!("Failed to process transaction {}: {}", hash, e);
+                    let mut error_result = HashMap::new();
+                    error_result.insert("error".to_string(), format!("Processing failed: {}", e));
+                    batch_results.insert(hash, error_result);
+                }
+            }
+        }
+
+        info!(
+            "Completed batch processing of {} transactions ({} skipped by filter, concurrency {})",
+            batch_results.len(),
+            skipped,
+            self.concurrency
+        );
+        Ok(batch_results)
+    }
+
+    /// Tests whether a transaction's `from`/`to` addresses might be present
+    /// in `bloom`
+    fn matches_filter(tx: &Transaction, bloom: &Bloom) -> bool {
+        bloom.contains(tx.from.0.as_bytes())
+            || tx
+                .to
+                .as_ref()
+                .map_or(false, |to| bloom.contains(to.0.as_bytes()))
+    }
+}
+
+impl Default for TransactionProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Replays an opcode trace once and surfaces the raw signals that trace-aware
+/// analyzers (like `EvmTraceAnalyzer`) turn into findings.
+///
+/// Keeping the replay logic separate from `EvmTraceAnalyzer` lets other
+/// analyzers reuse the same pass over the trace instead of re-scanning it.
+pub struct TraceReplayDriver {
+    /// Number of times a `JUMP`/`JUMPI` must target the same PC with
+    /// non-decreasing gas burn before it's considered an unbounded loop
+    loop_repeat_threshold: u32,
+    /// Fraction of remaining gas a single step's cost must exceed to be
+    /// flagged as gas griefing
+    gas_griefing_fraction: f64,
+}
+
+/// Raw signals extracted from a single pass over a `TraceStep` sequence
+#[derive(Debug, Default, Clone)]
+pub struct TraceReplaySummary {
+    /// PCs of `JUMP`/`JUMPI` instructions that looped with growing gas burn
+    pub unbounded_loop_pcs: Vec<u64>,
+    /// `(call_pc, slot)` pairs where a storage slot read before a
+    /// `CALL`/`DELEGATECALL` was written back to after it returned
+    pub reentrancy_windows: Vec<(u64, String)>,
+    /// PCs of steps whose gas cost exceeded the configured griefing fraction
+    /// of the gas remaining at that point
+    pub gas_griefing_pcs: Vec<u64>,
+}
+
+impl TraceReplayDriver {
+    /// Creates a driver with sensible defaults: 3 repeats to flag a loop and
+    /// a single step burning more than half of remaining gas is griefing
+    pub fn new() -> Self {
+        Self {
+            loop_repeat_threshold: 3,
+            gas_griefing_fraction: 0.5,
+        }
+    }
+
+    /// Creates a driver with explicit thresholds
+    pub fn with_thresholds(loop_repeat_threshold: u32, gas_griefing_fraction: f64) -> Self {
+        Self {
+            loop_repeat_threshold,
+            gas_griefing_fraction,
+        }
+    }
+
+    /// Replays `trace` once, collecting loop, reentrancy and gas-griefing signals
+    pub fn replay(&self, trace: &[TraceStep]) -> TraceReplaySummary {
+        let mut summary = TraceReplaySummary::default();
+
+        // Unbounded loops: JUMP/JUMPI steps that repeatedly target the same
+        // PC with a non-decreasing gas cost.
+        let mut jump_costs: HashMap<u64, Vec<u64>> = HashMap::new();
+        for step in trace {
+            if step.opcode == "JUMP" || step.opcode == "JUMPI" {
+                jump_costs.entry(step.pc).or_default().push(step.gas_cost);
+            }
+        }
+        let mut looping_pcs: Vec<u64> = jump_costs
+            .into_iter()
+            .filter(|(_, costs)| {
+                costs.len() as u32 >= self.loop_repeat_threshold
+                    && costs.windows(2).all(|w| w[1] >= w[0])
+            })
+            .map(|(pc, _)| pc)
+            .collect();
+        looping_pcs.sort_unstable();
+        summary.unbounded_loop_pcs = looping_pcs;
+
+        // Reentrancy windows: a slot read before a CALL/DELEGATECALL gets
+        // written to by an SSTORE after that call.
+        let mut read_slots: std::collections::HashSet<String> = std::collections::HashSet::new();
+        let mut read_before_call: std::collections::HashSet<String> = std::collections::HashSet::new();
+        let mut last_call_pc: Option<u64> = None;
+        for step in trace {
+            match step.opcode.as_str() {
+                "SLOAD" => {
+                    for slot in &step.storage_writes {
+                        read_slots.insert(slot.clone());
+                    }
+                }
+                "CALL" | "DELEGATECALL" => {
+                    last_call_pc = Some(step.pc);
+                    read_before_call = read_slots.clone();
+                }
+                "SSTORE" => {
+                    if let Some(call_pc) = last_call_pc {
+                        for slot in &step.storage_writes {
+                            if read_before_call.contains(slot) {
+                                summary.reentrancy_windows.push((call_pc, slot.clone()));
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Gas griefing: a single step whose cost eats an outsized fraction
+        // of the gas that remained before it ran.
+        for step in trace {
+            if step.gas_remaining > 0
+                && step.gas_cost as f64 > self.gas_griefing_fraction * step.gas_remaining as f64
+            {
+                summary.gas_griefing_pcs.push(step.pc);
+            }
+        }
+
+        summary
+    }
+}
+
+impl Default for TraceReplayDriver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Analyzer that replays a transaction's EVM execution trace looking for
+/// unbounded loops, reentrancy windows, and gas-griefing steps
+///
+/// Unlike analyzers that only see the static `Transaction` fields, this one
+/// overrides `analyze_trace` to inspect the actual sequence of opcodes
+/// executed — giving real instruction-level findings instead of a
+/// placeholder string map.
+pub struct EvmTraceAnalyzer {
+    driver: TraceReplayDriver,
+}
+
+impl EvmTraceAnalyzer {
+    /// Creates a new analyzer with the default replay thresholds
+    pub fn new() -> Self {
+        info!("Initializing EVM Trace Analyzer");
+        Self {
+            driver: TraceReplayDriver::new(),
+        }
+    }
+
+    /// Creates a new analyzer with explicit loop/griefing thresholds
+    pub fn with_thresholds(loop_repeat_threshold: u32, gas_griefing_fraction: f64) -> Self {
+        Self {
+            driver: TraceReplayDriver::with_thresholds(loop_repeat_threshold, gas_griefing_fraction),
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionAnalyzer for EvmTraceAnalyzer {
+    async fn analyze_transaction(&self, tx: &Transaction) -> Result<HashMap<String, String>> {
+        debug!("No trace available for transaction {}, skipping opcode-level analysis", tx.hash);
+        let mut results = HashMap::new();
+        results.insert("trace_analysis".to_string(), "no_trace_provided".to_string());
+        Ok(results)
+    }
+
+    #[instrument(skip(self, tx, trace), fields(tx_hash = %tx.hash, steps = trace.len()), level = "debug")]
+    async fn analyze_trace(
+        &self,
+        tx: &Transaction,
+        trace: &[TraceStep],
+    ) -> Result<HashMap<String, String>> {
+        let _timing_span = create_timing_span("trace_analysis", "evm_replay");
+        info!("Replaying {}-step trace for transaction {}", trace.len(), tx.hash);
+
+        let summary = self.driver.replay(trace);
+
+        let mut results = HashMap::new();
+        results.insert(
+            "unbounded_loop_count".to_string(),
+            summary.unbounded_loop_pcs.len().to_string(),
+        );
+        results.insert(
+            "unbounded_loop_pcs".to_string(),
+            format!("{:?}", summary.unbounded_loop_pcs),
+        );
+        results.insert(
+            "reentrancy_window_count".to_string(),
+            summary.reentrancy_windows.len().to_string(),
+        );
+        results.insert(
+            "reentrancy_windows".to_string(),
+            format!("{:?}", summary.reentrancy_windows),
+        );
+        results.insert(
+            "gas_griefing_count".to_string(),
+            summary.gas_griefing_pcs.len().to_string(),
+        );
+        results.insert(
+            "gas_griefing_pcs".to_string(),
+            format!("{:?}", summary.gas_griefing_pcs),
+        );
+
+        debug!(
+            "Trace analysis of transaction {} found {} loop(s), {} reentrancy window(s), {} griefing step(s)",
+            tx.hash,
+            summary.unbounded_loop_pcs.len(),
+            summary.reentrancy_windows.len(),
+            summary.gas_griefing_pcs.len()
+        );
+
+        Ok(results)
+    }
+}
+
+impl Default for EvmTraceAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::types::{Address, Hash};
+
+    struct MockAnalyzer {
+        key: String,
+        value: String,
+    }
+
+    #[async_trait]
+    impl TransactionAnalyzer for MockAnalyzer {
+        async fn analyze_transaction(&self, _tx: &Transaction) -> Result<HashMap<String, String>> {
+            let mut results = HashMap::new();
+            results.insert(self.key.clone(), self.value.clone());
+            Ok(results)
+        }
+    }
+
+    struct MockFindingAnalyzer {
+        risk: RiskLevel,
+    }
+
+    #[async_trait]
+    impl TransactionAnalyzer for MockFindingAnalyzer {
+        async fn analyze_transaction(&self, _tx: &Transaction) -> Result<HashMap<String, String>> {
+            Ok(HashMap::new())
+        }
+
+        async fn analyze(&self, _tx: &Transaction) -> Result<Vec<Finding>> {
+            Ok(vec![Finding {
+                id: "mock_finding".to_string(),
+                title: "Mock finding".to_string(),
+                risk: self.risk,
+                detail: "mock detail".to_string(),
+                location: None,
+            }])
+        }
+    }
+
+    fn sample_tx() -> Transaction {
+        Transaction::new(
+            Hash("0x123".to_string()),
+            Address("0xabc".to_string()),
+            Some(Address("0xdef".to_string())),
+            1000,
+            50,
+            21000,
+            5,
+            vec![],
+        )
+    }
+
+    #[tokio::test]
+    async fn test_empty_processor() {
+        let processor = TransactionProcessor::new();
+        let results = processor.process_transaction(&sample_tx()).await.unwrap();
+        assert!(results.contains_key("status"));
+        assert_eq!(results.get("status").unwrap(), "No analyzers configured");
+    }
+
+    #[tokio::test]
+    async fn test_multiple_analyzers() {
+        let mut processor = TransactionProcessor::new();
+
+        processor.register_analyzer(Arc::new(MockAnalyzer {
+            key: "gas_analysis".to_string(),
+            value: "efficient".to_string(),
+        }));
+
+        processor.register_analyzer(Arc::new(MockAnalyzer {
+            key: "security".to_string(),
+            value: "safe".to_string(),
+        }));
+
+        let results = processor.process_transaction(&sample_tx()).await.unwrap();
+        assert_eq!(results.get("gas_analysis").unwrap(), "efficient");
+        assert_eq!(results.get("security").unwrap(), "safe");
+    }
+
+    #[tokio::test]
+    async fn test_analyze_aggregates_findings_and_computes_overall_risk() {
+        let mut processor = TransactionProcessor::new();
+        processor.register_analyzer(Arc::new(MockAnalyzer {
+            key: "analysis".to_string(),
+            value: "complete".to_string(),
+        }));
+        processor.register_analyzer(Arc::new(MockFindingAnalyzer { risk: RiskLevel::Low }));
+        processor.register_analyzer(Arc::new(MockFindingAnalyzer { risk: RiskLevel::High }));
+
+        let report = processor.analyze(&sample_tx()).await.unwrap();
+
+        assert_eq!(report.risk, RiskLevel::High);
+        assert_eq!(report.findings.len(), 2);
+        assert_eq!(report.combined.get("analysis").unwrap(), "complete");
+    }
+
+    #[tokio::test]
+    async fn test_analyze_with_no_findings_has_none_risk() {
+        let mut processor = TransactionProcessor::new();
+        processor.register_analyzer(Arc::new(MockAnalyzer {
+            key: "analysis".to_string(),
+            value: "complete".to_string(),
+        }));
+
+        let report = processor.analyze(&sample_tx()).await.unwrap();
+
+        assert_eq!(report.risk, RiskLevel::None);
+        assert!(report.findings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_batch_processing() {
+        let mut processor = TransactionProcessor::new();
+
+        processor.register_analyzer(Arc::new(MockAnalyzer {
+            key: "analysis".to_string(),
+            value: "complete".to_string(),
+        }));
+
+        let transactions = vec![
+            sample_tx(),
+            Transaction::new(
+                Hash("0x456".to_string()),
+                Address("0xabc".to_string()),
+                Some(Address("0xdef".to_string())),
+                2000,
+                60,
+                21000,
+                6,
+                vec![],
+            ),
+        ];
+
+        let batch_results = processor.process_batch(&transactions, None).await.unwrap();
+        assert_eq!(batch_results.len(), 2);
+        assert!(batch_results.contains_key("0x123"));
+        assert!(batch_results.contains_key("0x456"));
+
+        for (_, results) in batch_results {
+            assert_eq!(results.get("analysis").unwrap(), "complete");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_processing_with_bloom_filter() {
+        let mut processor = TransactionProcessor::new();
+        processor.register_analyzer(Arc::new(MockAnalyzer {
+            key: "analysis".to_string(),
+            value: "complete".to_string(),
+        }));
+
+        let transactions = vec![
+            sample_tx(),
+            Transaction::new(
+                Hash("0x456".to_string()),
+                Address("0xnotinvolved".to_string()),
+                None,
+                2000,
+                60,
+                21000,
+                6,
+                vec![],
+            ),
+        ];
+
+        let mut filter = Bloom::new();
+        filter.insert(b"0xabc");
+
+        let batch_results = processor
+            .process_batch(&transactions, Some(&filter))
+            .await
+            .unwrap();
+
+        assert_eq!(batch_results.len(), 1);
+        assert!(batch_results.contains_key("0x123"));
+    }
+
+    fn step(pc: u64, opcode: &str, gas_remaining: u64, gas_cost: u64, storage: &[&str]) -> TraceStep {
+        TraceStep {
+            pc,
+            opcode: opcode.to_string(),
+            gas_remaining,
+            gas_cost,
+            stack_depth: 1,
+            storage_writes: storage.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unbounded_loop_detection() {
+        let driver = TraceReplayDriver::new();
+        let trace = vec![
+            step(10, "JUMPI", 100_000, 10, &[]),
+            step(10, "JUMPI", 99_000, 12, &[]),
+            step(10, "JUMPI", 98_000, 15, &[]),
+        ];
+        let summary = driver.replay(&trace);
+        assert_eq!(summary.unbounded_loop_pcs, vec![10]);
+    }
+
+    #[tokio::test]
+    async fn test_reentrancy_window_detection() {
+        let driver = TraceReplayDriver::new();
+        let trace = vec![
+            step(1, "SLOAD", 100_000, 200, &["0x0"]),
+            step(2, "CALL", 99_000, 2600, &[]),
+            step(3, "SSTORE", 50_000, 5000, &["0x0"]),
+        ];
+        let summary = driver.replay(&trace);
+        assert_eq!(summary.reentrancy_windows, vec![(2, "0x0".to_string())]);
+    }
+
+    #[tokio::test]
+    async fn test_gas_griefing_detection() {
+        let driver = TraceReplayDriver::new();
+        let trace = vec![step(1, "CALL", 10_000, 9_000, &[])];
+        let summary = driver.replay(&trace);
+        assert_eq!(summary.gas_griefing_pcs, vec![1]);
+    }
+
+    #[tokio::test]
+    async fn test_evm_trace_analyzer() {
+        let analyzer = EvmTraceAnalyzer::new();
+        let trace = vec![
+            step(1, "SLOAD", 100_000, 200, &["0x0"]),
+            step(2, "CALL", 99_000, 2600, &[]),
+            step(3, "SSTORE", 50_000, 5000, &["0x0"]),
+        ];
+
+        let results = analyzer.analyze_trace(&sample_tx(), &trace).await.unwrap();
+        assert_eq!(results.get("reentrancy_window_count").unwrap(), "1");
+    }
+}
@@ -0,0 +1,240 @@
+// transaction-analyzer/src/signature.rs
+use async_trait::async_trait;
+use blockchain_core::models::Transaction;
+use common::{
+    crypto::{is_malleable, recover_signer},
+    error::Result,
+    logging::create_timing_span,
+    types::{Address, Hash, RiskLevel},
+};
+use std::collections::HashMap;
+use tracing::{debug, info, instrument};
+
+use crate::findings::Finding;
+use crate::processor::TransactionAnalyzer;
+
+/// Runs signature recovery once for `tx`, shared by `analyze_transaction`
+/// and `analyze`. Returns `None` when the transaction carries no signature.
+///
+/// Delegates the actual secp256k1 recovery and EIP-2 malleability check to
+/// `common::crypto`, which other crates (not just transaction analysis)
+/// need the same verification from.
+fn verify_signature(tx: &Transaction) -> Option<(bool, Result<Address>)> {
+    let sig = tx.signature.as_ref()?;
+    let malleable = is_malleable(&sig.s);
+    let signing_hash = Hash(format!("0x{}", hex::encode(tx.signing_hash())));
+    Some((malleable, recover_signer(&signing_hash, sig.r, sig.s, sig.v)))
+}
+
+/// Analyzer that cryptographically verifies a transaction's sender
+///
+/// Recovers the secp256k1 public key from the transaction's `(r, s, v)`
+/// signature over [`Transaction::signing_hash`](blockchain_core::models::Transaction::signing_hash)
+/// and compares the derived address against the declared `from` field,
+/// instead of trusting it as self-reported.
+///
+/// # Examples
+///
+/// ```
+/// use transaction_analyzer::signature::SignatureAnalyzer;
+/// use transaction_analyzer::processor::TransactionAnalyzer;
+/// use blockchain_core::models::Transaction;
+/// use common::types::{Address, Hash};
+///
+/// # tokio_test::block_on(async {
+/// let analyzer = SignatureAnalyzer::new();
+///
+/// // A transaction with no signature attached can't be verified.
+/// let tx = Transaction::new(
+///     Hash("0x123".to_string()),
+///     Address("0xabc".to_string()),
+///     Some(Address("0xdef".to_string())),
+///     1000,
+///     50,
+///     21000,
+///     5,
+///     vec![],
+/// );
+///
+/// let results = analyzer.analyze_transaction(&tx).await.unwrap();
+/// assert_eq!(results.get("signature_analysis").unwrap(), "no_signature_provided");
+/// # })
+/// ```
+pub struct SignatureAnalyzer;
+
+impl SignatureAnalyzer {
+    /// Creates a new signature analyzer
+    pub fn new() -> Self {
+        info!("Initializing Signature Analyzer");
+        Self
+    }
+}
+
+#[async_trait]
+impl TransactionAnalyzer for SignatureAnalyzer {
+    #[instrument(skip(self, tx), fields(tx_hash = %tx.hash), level = "debug")]
+    async fn analyze_transaction(&self, tx: &Transaction) -> Result<HashMap<String, String>> {
+        let _timing_span = create_timing_span("signature_analysis", "transaction");
+        let mut results = HashMap::new();
+
+        let Some((malleable, recovery)) = verify_signature(tx) else {
+            debug!("No signature attached to transaction {}, skipping verification", tx.hash);
+            results.insert("signature_analysis".to_string(), "no_signature_provided".to_string());
+            return Ok(results);
+        };
+
+        results.insert("malleable_signature".to_string(), malleable.to_string());
+
+        match recovery {
+            Ok(recovered) => {
+                let signature_valid = recovered.0.eq_ignore_ascii_case(&tx.from.0);
+                results.insert("signature_valid".to_string(), signature_valid.to_string());
+                results.insert("recovered_sender".to_string(), recovered.to_string());
+
+                let risk_level = if !signature_valid {
+                    RiskLevel::High
+                } else if malleable {
+                    RiskLevel::Medium
+                } else {
+                    RiskLevel::None
+                };
+                results.insert("risk_level".to_string(), risk_level.to_string());
+
+                debug!(
+                    "Signature analysis of transaction {}: valid={}, recovered={}, malleable={}",
+                    tx.hash, signature_valid, recovered, malleable
+                );
+            }
+            Err(e) => {
+                results.insert("signature_valid".to_string(), "false".to_string());
+                results.insert("signature_error".to_string(), e.to_string());
+                results.insert("risk_level".to_string(), RiskLevel::High.to_string());
+            }
+        }
+
+        Ok(results)
+    }
+
+    #[instrument(skip(self, tx), fields(tx_hash = %tx.hash), level = "debug")]
+    async fn analyze(&self, tx: &Transaction) -> Result<Vec<Finding>> {
+        let Some((malleable, recovery)) = verify_signature(tx) else {
+            return Ok(Vec::new());
+        };
+
+        let finding = match recovery {
+            Ok(recovered) => {
+                let signature_valid = recovered.0.eq_ignore_ascii_case(&tx.from.0);
+                let risk = if !signature_valid {
+                    RiskLevel::High
+                } else if malleable {
+                    RiskLevel::Medium
+                } else {
+                    RiskLevel::None
+                };
+
+                Finding {
+                    id: "signature_recovery".to_string(),
+                    title: "ECDSA signature verification".to_string(),
+                    risk,
+                    detail: format!(
+                        "recovered sender {} {} declared from {}{}",
+                        recovered,
+                        if signature_valid { "matches" } else { "does not match" },
+                        tx.from,
+                        if malleable { "; signature is malleable (high-s)" } else { "" }
+                    ),
+                    location: None,
+                }
+            }
+            Err(e) => Finding {
+                id: "signature_recovery".to_string(),
+                title: "ECDSA signature verification".to_string(),
+                risk: RiskLevel::High,
+                detail: format!("signature recovery failed: {}", e),
+                location: None,
+            },
+        };
+
+        Ok(vec![finding])
+    }
+}
+
+impl Default for SignatureAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use blockchain_core::models::SignatureComponents;
+
+    fn sample_tx() -> Transaction {
+        Transaction::new(
+            Hash("0x123".to_string()),
+            Address("0xabc".to_string()),
+            Some(Address("0xdef".to_string())),
+            1000,
+            50,
+            21000,
+            5,
+            vec![],
+        )
+    }
+
+    #[tokio::test]
+    async fn test_unsigned_transaction_is_skipped() {
+        let analyzer = SignatureAnalyzer::new();
+        let results = analyzer.analyze_transaction(&sample_tx()).await.unwrap();
+        assert_eq!(results.get("signature_analysis").unwrap(), "no_signature_provided");
+    }
+
+    #[tokio::test]
+    async fn test_malformed_signature_is_flagged_high_risk() {
+        let analyzer = SignatureAnalyzer::new();
+        // All-zero r/s is not a valid scalar pair, so recovery must fail
+        // closed rather than silently accepting a forged sender.
+        let tx = sample_tx().with_signature(SignatureComponents {
+            r: [0u8; 32],
+            s: [0u8; 32],
+            v: 0,
+        });
+
+        let results = analyzer.analyze_transaction(&tx).await.unwrap();
+        assert_eq!(results.get("signature_valid").unwrap(), "false");
+        assert_eq!(results.get("risk_level").unwrap(), "High");
+    }
+
+    #[tokio::test]
+    async fn test_unsigned_transaction_yields_no_findings() {
+        let analyzer = SignatureAnalyzer::new();
+        let findings = analyzer.analyze(&sample_tx()).await.unwrap();
+        assert!(findings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_malformed_signature_yields_high_risk_finding() {
+        let analyzer = SignatureAnalyzer::new();
+        let tx = sample_tx().with_signature(SignatureComponents {
+            r: [0u8; 32],
+            s: [0u8; 32],
+            v: 0,
+        });
+
+        let findings = analyzer.analyze(&tx).await.unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].id, "signature_recovery");
+        assert_eq!(findings[0].risk, RiskLevel::High);
+    }
+
+    #[test]
+    fn test_malleability_bound() {
+        let low_s = [0u8; 32];
+        let mut high_s = [0xFFu8; 32];
+        high_s[0] = 0xFF;
+
+        assert!(!is_malleable(&low_s));
+        assert!(is_malleable(&high_s));
+    }
+}